@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use cora::linalg::dot::dot_slice;
+
+// Run with and without `--features simd` to compare the unrolled kernel against the scalar loop.
+fn dot(c: &mut Criterion) {
+    let a: Vec<f64> = (0..1_000_000).map(|i| (i % 10) as f64).collect();
+    let b = a.clone();
+    c.bench_function("dot_slice 1e6", |bench| {
+        bench.iter(|| dot_slice(black_box(&a), black_box(&b)))
+    });
+}
+
+criterion_group!(benches, dot);
+criterion_main!(benches);
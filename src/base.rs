@@ -1,3 +1,9 @@
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::metrics::classification::accuracy;
+use crate::metrics::regression::r2_score;
+use crate::numbers::Real;
+
 pub trait BaseEstimator<M, P, E> {
     fn fit(self, x: &M, y: &M, fit_params: P) -> Result<Self, E>
     where
@@ -5,9 +11,90 @@ pub trait BaseEstimator<M, P, E> {
 }
 
 pub trait Classifier<M, E> {
-    fn predict(self, x: &M) -> Result<M, E>;
+    fn predict(&self, x: &M) -> Result<M, E>;
+
+    /// Predict one row of `x` at a time, yielding the matching row of the `predict` output. The iterator borrows the
+    /// model and `x`, so large inputs can be scored without allocating the full prediction matrix.
+    fn predict_iter<'a, T, V>(&'a self, x: &'a M) -> Box<dyn Iterator<Item = Result<V, E>> + 'a>
+    where
+        Self: Sized,
+        T: Real,
+        M: BaseMatrix<T>,
+        V: BaseVector<T>,
+    {
+        let (nrows, ncols) = x.shape();
+        Box::new((0..nrows).map(move |i| {
+            let mut row = M::zeros(1, ncols);
+            for j in 0..ncols {
+                row.set(0, j, x.get(i, j));
+            }
+            self.predict(&row).map(|p| p.get_row(0))
+        }))
+    }
+
+    /// Accuracy of the predicted labels for `x` against `y`.
+    fn score<T, V>(&self, x: &M, y: &V) -> Result<T, Failure>
+    where
+        Self: Sized,
+        T: Real,
+        M: BaseMatrix<T>,
+        V: BaseVector<T>,
+        E: Into<Failure>,
+    {
+        let y_pred: V = predict_column(self.predict(x).map_err(Into::into)?, y)?;
+        Ok(accuracy(y, &y_pred))
+    }
+}
+
+/// Classifier that also estimates class membership probabilities.
+pub trait ProbabilisticClassifier<M, E>: Classifier<M, E> {
+    /// Probability of every class for each row of `x`, one column per class and rows summing to one. The column
+    /// order is documented by each implementation.
+    fn predict_proba(&self, x: &M) -> Result<M, E>;
 }
 
 pub trait Regressor<M, E> {
-    fn predict(self, x: &M) -> Result<M, E>;
+    fn predict(&self, x: &M) -> Result<M, E>;
+
+    /// Coefficient of determination \\( R^2 \\) of the predictions for `x` against `y`.
+    fn score<T, V>(&self, x: &M, y: &V) -> Result<T, Failure>
+    where
+        Self: Sized,
+        T: Real,
+        M: BaseMatrix<T>,
+        V: BaseVector<T>,
+        E: Into<Failure>,
+    {
+        let y_pred: V = predict_column(self.predict(x).map_err(Into::into)?, y)?;
+        Ok(r2_score(y, &y_pred))
+    }
+}
+
+/// First column of the prediction matrix, checked against the number of targets in `y`.
+fn predict_column<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    y_hat: M,
+    y: &V,
+) -> Result<V, Failure> {
+    let (nrows, _) = y_hat.shape();
+    if nrows != y.len() {
+        return Err(Failure::invalid_input(&format!(
+            "y has {} samples, x has {}",
+            y.len(),
+            nrows
+        )));
+    }
+    Ok(y_hat.get_col(0))
+}
+
+pub trait Transformer<M, E> {
+    fn transform(&self, x: &M) -> Result<M, E>;
+
+    /// Fit on `x` and `y`, then transform `x` with the fitted result. Stateful transformers must store everything
+    /// `transform` needs during `fit`.
+    fn fit_transform<P>(self, x: &M, y: &M, fit_params: P) -> Result<M, E>
+    where
+        Self: BaseEstimator<M, P, E> + Sized,
+    {
+        self.fit(x, y, fit_params)?.transform(x)
+    }
 }
@@ -0,0 +1,229 @@
+//! # K-Means Clustering
+//! Partitions samples into `n_clusters` groups with [Lloyd's algorithm](https://en.wikipedia.org/wiki/K-means_clustering),
+//! starting from centroids chosen by [k-means++](https://en.wikipedia.org/wiki/K-means%2B%2B).
+
+use std::marker::PhantomData;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::base::BaseEstimator;
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::metrics::distance::{Distance, Euclidean};
+use crate::numbers::Real;
+
+/// K-means parameters
+#[derive(Clone, Debug)]
+pub struct KMeansParameters<T: Real> {
+    /// Number of clusters.
+    pub n_clusters: usize,
+    /// Maximum number of Lloyd iterations.
+    pub max_iter: usize,
+    /// Stop when no centroid moves further than this distance.
+    pub tol: T,
+    /// Seed of the random number generator used for k-means++ initialization.
+    pub seed: u64,
+}
+
+impl<T: Real> Default for KMeansParameters<T> {
+    fn default() -> Self {
+        KMeansParameters {
+            n_clusters: 8,
+            max_iter: 300,
+            tol: T::from_f64(1e-4).unwrap(),
+            seed: 0,
+        }
+    }
+}
+
+/// K-means clustering
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KMeans<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> {
+    centroids: M,
+    _phantom: PhantomData<(T, V)>,
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Default for KMeans<T, M, V> {
+    fn default() -> Self {
+        KMeans {
+            centroids: M::zeros(0, 0),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> KMeans<T, M, V> {
+    /// Fitted centroids, one row per cluster.
+    pub fn centroids(&self) -> &M {
+        &self.centroids
+    }
+
+    /// Index of the nearest centroid for every row of `x`.
+    pub fn predict(&self, x: &M) -> Result<V, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (_, n_features) = self.centroids.shape();
+        if ncols != n_features {
+            return Err(Failure::predict(&format!(
+                "x has {} features, model was fitted with {}",
+                ncols, n_features
+            )));
+        }
+        let mut labels = V::zeros(nrows);
+        for i in 0..nrows {
+            let (label, _) = nearest(&self.centroids, &x.get_row::<V>(i));
+            labels.set(i, T::from_usize(label).unwrap());
+        }
+        Ok(labels)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> BaseEstimator<M, KMeansParameters<T>, Failure>
+    for KMeans<T, M, V>
+{
+    /// Cluster the rows of `x`, `y` is ignored.
+    fn fit(self, x: &M, _y: &M, fit_params: KMeansParameters<T>) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let k = fit_params.n_clusters;
+        if k == 0 || k > nrows {
+            return Err(Failure::fit(&format!(
+                "n_clusters must be in [1, {}], got {}",
+                nrows, k
+            )));
+        }
+
+        let rows: Vec<V> = (0..nrows).map(|i| x.get_row(i)).collect();
+        let mut rng = StdRng::seed_from_u64(fit_params.seed);
+        let mut centroids = M::zeros(k, ncols);
+        centroids.set_row(0, &rows[rng.gen_range(0, nrows)]);
+
+        let mut d2 = vec![T::infinity(); nrows];
+        for c in 1..k {
+            let last: V = centroids.get_row(c - 1);
+            for (i, row) in rows.iter().enumerate() {
+                d2[i] = d2[i].min(Euclidean.distance(row, &last).square());
+            }
+            let total: T = d2.iter().copied().sum();
+            let mut target = T::rand_with(&mut rng) * total;
+            let mut chosen = nrows - 1;
+            for (i, &d) in d2.iter().enumerate() {
+                if target < d {
+                    chosen = i;
+                    break;
+                }
+                target -= d;
+            }
+            centroids.set_row(c, &rows[chosen]);
+        }
+
+        for _ in 0..fit_params.max_iter {
+            let mut sums = M::zeros(k, ncols);
+            let mut counts = vec![0usize; k];
+            for row in rows.iter() {
+                let (label, _) = nearest(&centroids, row);
+                counts[label] += 1;
+                for j in 0..ncols {
+                    sums.set(label, j, sums.get(label, j) + row.get(j));
+                }
+            }
+
+            let mut shift = T::zero();
+            for (c, &count) in counts.iter().enumerate() {
+                if count == 0 {
+                    return Err(Failure::fit(&format!(
+                        "cluster {} is empty after reassignment",
+                        c
+                    )));
+                }
+                let mut centroid = sums.get_row::<V>(c);
+                centroid.div_scalar_mut(T::from_usize(count).unwrap());
+                shift = shift.max(Euclidean.distance(&centroid, &centroids.get_row(c)));
+                centroids.set_row(c, &centroid);
+            }
+            if shift <= fit_params.tol {
+                break;
+            }
+        }
+
+        Ok(KMeans {
+            centroids,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// Index of and distance to the row of `centroids` closest to `x`.
+fn nearest<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(centroids: &M, x: &V) -> (usize, T) {
+    let (k, _) = centroids.shape();
+    let mut best = (0, T::infinity());
+    for c in 0..k {
+        let d = Euclidean.distance(x, &centroids.get_row(c));
+        if d < best.1 {
+            best = (c, d);
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_distr::StandardNormal;
+
+    #[test]
+    fn three_blobs() {
+        let centers = [[0., 0.], [10., 10.], [-10., 10.]];
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut x: Vec<Vec<f64>> = Vec::new();
+        for center in centers.iter() {
+            for _ in 0..30 {
+                x.push(
+                    center
+                        .iter()
+                        .map(|c| c + 0.5 * rng.sample::<f64, _>(StandardNormal))
+                        .collect(),
+                );
+            }
+        }
+
+        let kmeans: KMeans<f64, Vec<Vec<f64>>, Vec<f64>> = KMeans::default()
+            .fit(
+                &x,
+                &x,
+                KMeansParameters {
+                    n_clusters: 3,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let labels = kmeans.predict(&x).unwrap();
+        for (b, center) in centers.iter().enumerate() {
+            let label = labels[30 * b];
+            assert!(labels[30 * b..30 * (b + 1)].iter().all(|&l| l == label));
+            let centroid: Vec<f64> = kmeans.centroids().get_row(label as usize);
+            assert!(centroid.approximate_eq(&center.to_vec(), 0.5));
+        }
+        let mut distinct = vec![labels[0], labels[30], labels[60]];
+        distinct.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        distinct.dedup();
+        assert_eq!(distinct.len(), 3);
+    }
+
+    #[test]
+    fn too_many_clusters() {
+        let x: Vec<Vec<f64>> = BaseMatrix::ones(2, 2);
+        let err = KMeans::<f64, _, Vec<f64>>::default()
+            .fit(
+                &x,
+                &x,
+                KMeansParameters {
+                    n_clusters: 3,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err, Failure::fit("n_clusters must be in [1, 2], got 3"));
+    }
+}
@@ -0,0 +1,4 @@
+//! # Clustering
+//! Unsupervised estimators that group samples by similarity.
+
+pub mod kmeans;
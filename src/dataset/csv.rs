@@ -0,0 +1,160 @@
+//! # CSV Loader
+//! Reads delimited text files of numbers into a feature matrix and a target vector.
+
+use std::fs;
+
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// CSV parsing options
+#[derive(Clone, Debug)]
+pub struct CsvOptions {
+    /// Field separator, `,` by default.
+    pub delimiter: char,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions { delimiter: ',' }
+    }
+}
+
+/// Load a comma separated file, splitting column `target_col` out as the target.
+/// Blank lines are skipped. Errors report 1-based line numbers of the file, counting the header and blank lines.
+pub fn load_csv<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    path: &str,
+    target_col: usize,
+    has_header: bool,
+) -> Result<(M, V), Failure> {
+    load_csv_with_options(path, target_col, has_header, CsvOptions::default())
+}
+
+/// Load a delimited file, splitting column `target_col` out as the target.
+/// Blank lines are skipped. Errors report 1-based line numbers of the file, counting the header and blank lines.
+pub fn load_csv_with_options<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    path: &str,
+    target_col: usize,
+    has_header: bool,
+    options: CsvOptions,
+) -> Result<(M, V), Failure> {
+    let content = fs::read_to_string(path)?;
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    if has_header {
+        lines.next();
+    }
+
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for (line_no, line) in lines {
+        let mut row = Vec::new();
+        for (col, cell) in line.split(options.delimiter).enumerate() {
+            let value: f64 = cell.trim().parse().map_err(|_| {
+                Failure::invalid_input(&format!(
+                    "non-numeric value '{}' at line {}, column {}",
+                    cell.trim(),
+                    line_no + 1,
+                    col + 1
+                ))
+            })?;
+            row.push(T::from_f64(value).unwrap());
+        }
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(Failure::invalid_input(&format!(
+                    "line {} has {} columns, expected {}",
+                    line_no + 1,
+                    row.len(),
+                    first.len()
+                )));
+            }
+        }
+        rows.push(row);
+    }
+
+    let ncols = rows.first().map_or(0, |r| r.len());
+    if target_col >= ncols {
+        return Err(Failure::invalid_input(&format!(
+            "target column {} out of bounds for {} columns",
+            target_col, ncols
+        )));
+    }
+
+    let mut x = M::zeros(rows.len(), ncols - 1);
+    let mut y = V::zeros(rows.len());
+    for (i, row) in rows.iter().enumerate() {
+        let mut j = 0;
+        for (c, &value) in row.iter().enumerate() {
+            if c == target_col {
+                y.set(i, value);
+            } else {
+                x.set(i, j, value);
+                j += 1;
+            }
+        }
+    }
+    Ok((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FailedError;
+    use std::env;
+
+    fn write_tmp(name: &str, content: &str) -> String {
+        let path = env::temp_dir().join(format!("cora-{}-{}.csv", name, std::process::id()));
+        fs::write(&path, content).unwrap();
+        path.to_str().unwrap().to_owned()
+    }
+
+    #[test]
+    fn load() {
+        let path = write_tmp("load", "a,b,y\n1,2,0\n3.5, 4,1\n\n5,6,0\n\n");
+        let (x, y): (Vec<Vec<f64>>, Vec<f64>) = load_csv(&path, 2, true).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(x, vec![vec![1., 2.], vec![3.5, 4.], vec![5., 6.]]);
+        assert_eq!(y, vec![0., 1., 0.]);
+    }
+
+    #[test]
+    fn load_with_delimiter() {
+        let path = write_tmp("load_delim", "7;1;2\n8;3;4\n");
+        let (x, y): (Vec<Vec<f64>>, Vec<f64>) =
+            load_csv_with_options(&path, 0, false, CsvOptions { delimiter: ';' }).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(x, vec![vec![1., 2.], vec![3., 4.]]);
+        assert_eq!(y, vec![7., 8.]);
+    }
+
+    #[test]
+    fn non_numeric() {
+        let path = write_tmp("load_bad", "1,2\n3,x\n");
+        let err = load_csv::<f64, Vec<Vec<f64>>, Vec<f64>>(&path, 1, false).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            err,
+            Failure::invalid_input("non-numeric value 'x' at line 2, column 2")
+        );
+    }
+
+    #[test]
+    fn error_line_counts_header() {
+        let path = write_tmp("load_ragged", "a,b\n1,2\n\n3\n");
+        let err = load_csv::<f64, Vec<Vec<f64>>, Vec<f64>>(&path, 1, true).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            err,
+            Failure::invalid_input("line 4 has 1 columns, expected 2")
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let err = load_csv::<f64, Vec<Vec<f64>>, Vec<f64>>("/nonexistent/cora.csv", 0, false)
+            .unwrap_err();
+        assert_eq!(err.error(), FailedError::IoFailed);
+    }
+}
@@ -0,0 +1,241 @@
+//! # Datasets
+//! Helpers to load training data into a feature matrix and a target vector, plus small built-in datasets for
+//! examples and tests.
+
+pub mod csv;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// Load the [Iris flower dataset](https://en.wikipedia.org/wiki/Iris_flower_data_set): 150 samples with 4 features
+/// (sepal length, sepal width, petal length, petal width, in cm) and labels 0 (setosa), 1 (versicolor) and 2 (virginica).
+pub fn load_iris<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>() -> (M, V) {
+    let mut x = M::zeros(IRIS.len(), 4);
+    let mut y = V::zeros(IRIS.len());
+    for (i, row) in IRIS.iter().enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            x.set(i, j, T::from_f64(value).unwrap());
+        }
+        y.set(i, T::from_usize(i / 50).unwrap());
+    }
+    (x, y)
+}
+
+/// Generate a random linear regression problem. Features are drawn from the standard normal distribution, coefficients
+/// uniformly from `[0, 100)`, and gaussian noise with standard deviation `noise` is added to the target.
+/// The same `seed` always produces the same dataset.
+pub fn make_regression<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    n_samples: usize,
+    n_features: usize,
+    noise: T,
+    seed: u64,
+) -> (M, V) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut x = M::zeros(n_samples, n_features);
+    for i in 0..n_samples {
+        for j in 0..n_features {
+            let value: f64 = rng.sample(StandardNormal);
+            x.set(i, j, T::from_f64(value).unwrap());
+        }
+    }
+    let coef: Vec<T> = (0..n_features)
+        .map(|_| T::from_f64(100.).unwrap() * T::rand_with(&mut rng))
+        .collect();
+
+    let mut y = V::zeros(n_samples);
+    for i in 0..n_samples {
+        let mut yi = T::zero();
+        for (j, &c) in coef.iter().enumerate() {
+            yi += x.get(i, j) * c;
+        }
+        let eps: f64 = rng.sample(StandardNormal);
+        y.set(i, yi + noise * T::from_f64(eps).unwrap());
+    }
+    (x, y)
+}
+
+#[rustfmt::skip]
+static IRIS: [[f64; 4]; 150] = [
+    [5.1, 3.5, 1.4, 0.2],
+    [4.9, 3.0, 1.4, 0.2],
+    [4.7, 3.2, 1.3, 0.2],
+    [4.6, 3.1, 1.5, 0.2],
+    [5.0, 3.6, 1.4, 0.2],
+    [5.4, 3.9, 1.7, 0.4],
+    [4.6, 3.4, 1.4, 0.3],
+    [5.0, 3.4, 1.5, 0.2],
+    [4.4, 2.9, 1.4, 0.2],
+    [4.9, 3.1, 1.5, 0.1],
+    [5.4, 3.7, 1.5, 0.2],
+    [4.8, 3.4, 1.6, 0.2],
+    [4.8, 3.0, 1.4, 0.1],
+    [4.3, 3.0, 1.1, 0.1],
+    [5.8, 4.0, 1.2, 0.2],
+    [5.7, 4.4, 1.5, 0.4],
+    [5.4, 3.9, 1.3, 0.4],
+    [5.1, 3.5, 1.4, 0.3],
+    [5.7, 3.8, 1.7, 0.3],
+    [5.1, 3.8, 1.5, 0.3],
+    [5.4, 3.4, 1.7, 0.2],
+    [5.1, 3.7, 1.5, 0.4],
+    [4.6, 3.6, 1.0, 0.2],
+    [5.1, 3.3, 1.7, 0.5],
+    [4.8, 3.4, 1.9, 0.2],
+    [5.0, 3.0, 1.6, 0.2],
+    [5.0, 3.4, 1.6, 0.4],
+    [5.2, 3.5, 1.5, 0.2],
+    [5.2, 3.4, 1.4, 0.2],
+    [4.7, 3.2, 1.6, 0.2],
+    [4.8, 3.1, 1.6, 0.2],
+    [5.4, 3.4, 1.5, 0.4],
+    [5.2, 4.1, 1.5, 0.1],
+    [5.5, 4.2, 1.4, 0.2],
+    [4.9, 3.1, 1.5, 0.2],
+    [5.0, 3.2, 1.2, 0.2],
+    [5.5, 3.5, 1.3, 0.2],
+    [4.9, 3.6, 1.4, 0.1],
+    [4.4, 3.0, 1.3, 0.2],
+    [5.1, 3.4, 1.5, 0.2],
+    [5.0, 3.5, 1.3, 0.3],
+    [4.5, 2.3, 1.3, 0.3],
+    [4.4, 3.2, 1.3, 0.2],
+    [5.0, 3.5, 1.6, 0.6],
+    [5.1, 3.8, 1.9, 0.4],
+    [4.8, 3.0, 1.4, 0.3],
+    [5.1, 3.8, 1.6, 0.2],
+    [4.6, 3.2, 1.4, 0.2],
+    [5.3, 3.7, 1.5, 0.2],
+    [5.0, 3.3, 1.4, 0.2],
+    [7.0, 3.2, 4.7, 1.4],
+    [6.4, 3.2, 4.5, 1.5],
+    [6.9, 3.1, 4.9, 1.5],
+    [5.5, 2.3, 4.0, 1.3],
+    [6.5, 2.8, 4.6, 1.5],
+    [5.7, 2.8, 4.5, 1.3],
+    [6.3, 3.3, 4.7, 1.6],
+    [4.9, 2.4, 3.3, 1.0],
+    [6.6, 2.9, 4.6, 1.3],
+    [5.2, 2.7, 3.9, 1.4],
+    [5.0, 2.0, 3.5, 1.0],
+    [5.9, 3.0, 4.2, 1.5],
+    [6.0, 2.2, 4.0, 1.0],
+    [6.1, 2.9, 4.7, 1.4],
+    [5.6, 2.9, 3.6, 1.3],
+    [6.7, 3.1, 4.4, 1.4],
+    [5.6, 3.0, 4.5, 1.5],
+    [5.8, 2.7, 4.1, 1.0],
+    [6.2, 2.2, 4.5, 1.5],
+    [5.6, 2.5, 3.9, 1.1],
+    [5.9, 3.2, 4.8, 1.8],
+    [6.1, 2.8, 4.0, 1.3],
+    [6.3, 2.5, 4.9, 1.5],
+    [6.1, 2.8, 4.7, 1.2],
+    [6.4, 2.9, 4.3, 1.3],
+    [6.6, 3.0, 4.4, 1.4],
+    [6.8, 2.8, 4.8, 1.4],
+    [6.7, 3.0, 5.0, 1.7],
+    [6.0, 2.9, 4.5, 1.5],
+    [5.7, 2.6, 3.5, 1.0],
+    [5.5, 2.4, 3.8, 1.1],
+    [5.5, 2.4, 3.7, 1.0],
+    [5.8, 2.7, 3.9, 1.2],
+    [6.0, 2.7, 5.1, 1.6],
+    [5.4, 3.0, 4.5, 1.5],
+    [6.0, 3.4, 4.5, 1.6],
+    [6.7, 3.1, 4.7, 1.5],
+    [6.3, 2.3, 4.4, 1.3],
+    [5.6, 3.0, 4.1, 1.3],
+    [5.5, 2.5, 4.0, 1.3],
+    [5.5, 2.6, 4.4, 1.2],
+    [6.1, 3.0, 4.6, 1.4],
+    [5.8, 2.6, 4.0, 1.2],
+    [5.0, 2.3, 3.3, 1.0],
+    [5.6, 2.7, 4.2, 1.3],
+    [5.7, 3.0, 4.2, 1.2],
+    [5.7, 2.9, 4.2, 1.3],
+    [6.2, 2.9, 4.3, 1.3],
+    [5.1, 2.5, 3.0, 1.1],
+    [5.7, 2.8, 4.1, 1.3],
+    [6.3, 3.3, 6.0, 2.5],
+    [5.8, 2.7, 5.1, 1.9],
+    [7.1, 3.0, 5.9, 2.1],
+    [6.3, 2.9, 5.6, 1.8],
+    [6.5, 3.0, 5.8, 2.2],
+    [7.6, 3.0, 6.6, 2.1],
+    [4.9, 2.5, 4.5, 1.7],
+    [7.3, 2.9, 6.3, 1.8],
+    [6.7, 2.5, 5.8, 1.8],
+    [7.2, 3.6, 6.1, 2.5],
+    [6.5, 3.2, 5.1, 2.0],
+    [6.4, 2.7, 5.3, 1.9],
+    [6.8, 3.0, 5.5, 2.1],
+    [5.7, 2.5, 5.0, 2.0],
+    [5.8, 2.8, 5.1, 2.4],
+    [6.4, 3.2, 5.3, 2.3],
+    [6.5, 3.0, 5.5, 1.8],
+    [7.7, 3.8, 6.7, 2.2],
+    [7.7, 2.6, 6.9, 2.3],
+    [6.0, 2.2, 5.0, 1.5],
+    [6.9, 3.2, 5.7, 2.3],
+    [5.6, 2.8, 4.9, 2.0],
+    [7.7, 2.8, 6.7, 2.0],
+    [6.3, 2.7, 4.9, 1.8],
+    [6.7, 3.3, 5.7, 2.1],
+    [7.2, 3.2, 6.0, 1.8],
+    [6.2, 2.8, 4.8, 1.8],
+    [6.1, 3.0, 4.9, 1.8],
+    [6.4, 2.8, 5.6, 2.1],
+    [7.2, 3.0, 5.8, 1.6],
+    [7.4, 2.8, 6.1, 1.9],
+    [7.9, 3.8, 6.4, 2.0],
+    [6.4, 2.8, 5.6, 2.2],
+    [6.3, 2.8, 5.1, 1.5],
+    [6.1, 2.6, 5.6, 1.4],
+    [7.7, 3.0, 6.1, 2.3],
+    [6.3, 3.4, 5.6, 2.4],
+    [6.4, 3.1, 5.5, 1.8],
+    [6.0, 3.0, 4.8, 1.8],
+    [6.9, 3.1, 5.4, 2.1],
+    [6.7, 3.1, 5.6, 2.4],
+    [6.9, 3.1, 5.1, 2.3],
+    [5.8, 2.7, 5.1, 1.9],
+    [6.8, 3.2, 5.9, 2.3],
+    [6.7, 3.3, 5.7, 2.5],
+    [6.7, 3.0, 5.2, 2.3],
+    [6.3, 2.5, 5.0, 1.9],
+    [6.5, 3.0, 5.2, 2.0],
+    [6.2, 3.4, 5.4, 2.3],
+    [5.9, 3.0, 5.1, 1.8],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iris() {
+        let (x, y): (Vec<Vec<f64>>, Vec<f64>) = load_iris();
+        assert_eq!(x.shape(), (150, 4));
+        assert_eq!(y.unique(), vec![0., 1., 2.]);
+
+        let setosa_petal_length: f64 = (0..50).map(|i| x[i][2]).sum::<f64>() / 50.;
+        assert!((setosa_petal_length - 1.462).abs() < 1e-10);
+    }
+
+    #[test]
+    fn regression() {
+        let (x, y): (Vec<Vec<f64>>, Vec<f64>) = make_regression(20, 3, 0.1, 42);
+        let (x2, y2): (Vec<Vec<f64>>, Vec<f64>) = make_regression(20, 3, 0.1, 42);
+        let (x3, _): (Vec<Vec<f64>>, Vec<f64>) = make_regression(20, 3, 0.1, 43);
+
+        assert_eq!(x.shape(), (20, 3));
+        assert_eq!(y.len(), 20);
+        assert_eq!(x, x2);
+        assert_eq!(y, y2);
+        assert_ne!(x, x3);
+    }
+}
@@ -0,0 +1,4 @@
+//! # Matrix Decomposition
+//! Transformers that project data onto a lower dimensional basis learned from the training set.
+
+pub mod pca;
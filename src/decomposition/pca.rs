@@ -0,0 +1,200 @@
+//! # Principal Component Analysis
+//! Projects data onto the `n_components` directions of largest variance, the top eigenvectors of the sample
+//! covariance matrix.
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Transformer};
+use crate::error::Failure;
+use crate::linalg::eigen::symmetric_eigen;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// PCA parameters
+#[derive(Clone, Debug)]
+pub struct PCAParameters {
+    /// Number of principal components to keep.
+    pub n_components: usize,
+}
+
+impl Default for PCAParameters {
+    fn default() -> Self {
+        PCAParameters { n_components: 2 }
+    }
+}
+
+/// Principal component analysis
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PCA<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> {
+    mean: Vec<T>,
+    components: M,
+    explained_variance_ratio: V,
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Default for PCA<T, M, V> {
+    fn default() -> Self {
+        PCA {
+            mean: Vec::new(),
+            components: M::zeros(0, 0),
+            explained_variance_ratio: V::zeros(0),
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> PCA<T, M, V> {
+    /// Principal axes as columns of a `(n_features, n_components)` matrix, sorted by explained variance.
+    /// Each axis is sign-stabilized so that its largest-magnitude loading is positive.
+    pub fn components(&self) -> &M {
+        &self.components
+    }
+
+    /// Fraction of the total variance explained by each component.
+    pub fn explained_variance_ratio(&self) -> &V {
+        &self.explained_variance_ratio
+    }
+
+    /// Map projected data back to the original feature space.
+    pub fn inverse_transform(&self, x: &M) -> Result<M, Failure> {
+        let (_, ncols) = x.shape();
+        let (_, n_components) = self.components.shape();
+        if ncols != n_components {
+            return Err(Failure::transform(&format!(
+                "x has {} components, PCA was fitted with {}",
+                ncols, n_components
+            )));
+        }
+        let mut r = x.matmul(&self.components.transpose());
+        let (nrows, n_features) = r.shape();
+        for i in 0..nrows {
+            for j in 0..n_features {
+                r.set(i, j, r.get(i, j) + self.mean[j]);
+            }
+        }
+        Ok(r)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> BaseEstimator<M, PCAParameters, Failure>
+    for PCA<T, M, V>
+{
+    /// Learn the principal axes of `x`, `y` is ignored.
+    fn fit(self, x: &M, _y: &M, fit_params: PCAParameters) -> Result<Self, Failure> {
+        let (nrows, n_features) = x.shape();
+        let k = fit_params.n_components;
+        if k == 0 || k > n_features {
+            return Err(Failure::fit(&format!(
+                "n_components must be in [1, {}], got {}",
+                n_features, k
+            )));
+        }
+
+        let n = T::from_usize(nrows).unwrap();
+        let mut mean = vec![T::zero(); n_features];
+        for (j, mu) in mean.iter_mut().enumerate() {
+            for i in 0..nrows {
+                *mu += x.get(i, j);
+            }
+            *mu /= n;
+        }
+
+        let cov = x.cov()?;
+        let (eigenvalues, eigenvectors) = symmetric_eigen(&cov)?;
+
+        let total: T = eigenvalues.iter().copied().sum();
+        let mut components = M::zeros(n_features, k);
+        let mut explained_variance_ratio = V::zeros(k);
+        for (c, &eigenvalue) in eigenvalues.iter().enumerate().take(k) {
+            let mut max_i = 0;
+            for i in 1..n_features {
+                if eigenvectors.get(i, c).abs() > eigenvectors.get(max_i, c).abs() {
+                    max_i = i;
+                }
+            }
+            let sign = if eigenvectors.get(max_i, c) < T::zero() {
+                -T::one()
+            } else {
+                T::one()
+            };
+            for i in 0..n_features {
+                components.set(i, c, sign * eigenvectors.get(i, c));
+            }
+            if total > T::zero() {
+                explained_variance_ratio.set(c, eigenvalue / total);
+            }
+        }
+
+        Ok(PCA {
+            mean,
+            components,
+            explained_variance_ratio,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Transformer<M, Failure> for PCA<T, M, V> {
+    fn transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = x.shape();
+        if ncols != self.mean.len() {
+            return Err(Failure::transform(&format!(
+                "x has {} features, PCA was fitted with {}",
+                ncols,
+                self.mean.len()
+            )));
+        }
+        let mut centered = x.clone();
+        for i in 0..nrows {
+            for j in 0..ncols {
+                centered.set(i, j, x.get(i, j) - self.mean[j]);
+            }
+        }
+        Ok(centered.matmul(&self.components))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plane_reconstruction() {
+        // points on the plane z = 2x - y + 1
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[
+            &[0., 0., 1.],
+            &[1., 0., 3.],
+            &[0., 1., 0.],
+            &[2., 1., 4.],
+            &[-1., 3., -4.],
+            &[3., -2., 9.],
+            &[1.5, 0.5, 3.5],
+        ]);
+        let pca: PCA<f64, _, Vec<f64>> = PCA::default()
+            .fit(&x, &x, PCAParameters { n_components: 2 })
+            .unwrap();
+        let z = pca.transform(&x).unwrap();
+        assert_eq!(z.shape(), (7, 2));
+
+        let x_back = pca.inverse_transform(&z).unwrap();
+        for i in 0..7 {
+            assert!(x_back[i].approximate_eq(&x[i], 1e-8));
+        }
+
+        let ratio = pca.explained_variance_ratio();
+        assert!((ratio.sum() - 1.).abs() < 1e-8);
+        assert!(ratio[0] >= ratio[1]);
+
+        for c in 0..2 {
+            let axis: Vec<f64> = pca.components().get_col(c);
+            assert!((axis.norm2() - 1.).abs() < 1e-8);
+            assert!(axis.get(axis.argmax()).abs() >= BaseVector::min(&axis).abs());
+        }
+    }
+
+    #[test]
+    fn invalid_n_components() {
+        let x: Vec<Vec<f64>> = BaseMatrix::ones(3, 2);
+        let err = PCA::<f64, _, Vec<f64>>::default()
+            .fit(&x, &x, PCAParameters { n_components: 3 })
+            .unwrap_err();
+        assert_eq!(err, Failure::fit("n_components must be in [1, 2], got 3"));
+    }
+}
@@ -0,0 +1,254 @@
+//! # Bagging Classifier
+//! [Bootstrap aggregating](https://en.wikipedia.org/wiki/Bootstrap_aggregating): fits independent copies of a base
+//! classifier on bootstrap resamples of the training data and predicts the majority label.
+
+use std::marker::PhantomData;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::base::{BaseEstimator, Classifier};
+use crate::ensemble::{bootstrap, majority_vote};
+use crate::error::{FailedError, Failure};
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Bagging classifier parameters
+#[derive(Clone, Debug)]
+pub struct BaggingClassifierParameters<P> {
+    /// Number of base estimators.
+    pub n_estimators: usize,
+    /// Seed of the generator drawing the bootstrap samples.
+    pub seed: u64,
+    /// Parameters passed to `fit` of every base estimator.
+    pub estimator_params: P,
+}
+
+impl<P: Default> Default for BaggingClassifierParameters<P> {
+    fn default() -> Self {
+        BaggingClassifierParameters {
+            n_estimators: 10,
+            seed: 0,
+            estimator_params: P::default(),
+        }
+    }
+}
+
+/// Majority vote over classifiers fitted on bootstrap samples
+pub struct BaggingClassifier<T: Real, M: BaseMatrix<T>, E> {
+    estimator_factory: Box<dyn Fn() -> E>,
+    estimators: Vec<E>,
+    _phantom: PhantomData<(T, M)>,
+}
+
+impl<T: Real, M: BaseMatrix<T>, E> BaggingClassifier<T, M, E> {
+    /// Create an unfitted ensemble whose base estimators are built by `estimator_factory`.
+    pub fn new<F: Fn() -> E + 'static>(estimator_factory: F) -> Self {
+        BaggingClassifier {
+            estimator_factory: Box::new(estimator_factory),
+            estimators: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Fitted base estimators.
+    pub fn estimators(&self) -> &[E] {
+        &self.estimators
+    }
+}
+
+impl<T, M, E, P> BaseEstimator<M, BaggingClassifierParameters<P>, Failure>
+    for BaggingClassifier<T, M, E>
+where
+    T: Real,
+    M: BaseMatrix<T>,
+    E: BaseEstimator<M, P, Failure>,
+    P: Clone,
+{
+    fn fit(
+        self,
+        x: &M,
+        y: &M,
+        fit_params: BaggingClassifierParameters<P>,
+    ) -> Result<Self, Failure> {
+        let (nrows, _) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if fit_params.n_estimators == 0 {
+            return Err(Failure::fit("n_estimators must be at least 1"));
+        }
+        if nrows == 0 {
+            return Err(Failure::fit("x must have at least one row"));
+        }
+
+        let mut rng = StdRng::seed_from_u64(fit_params.seed);
+        let mut estimators = Vec::with_capacity(fit_params.n_estimators);
+        for b in 0..fit_params.n_estimators {
+            let (x_sample, y_sample) = bootstrap(x, y, &mut rng);
+            let estimator = (self.estimator_factory)()
+                .fit(&x_sample, &y_sample, fit_params.estimator_params.clone())
+                .map_err(|err| {
+                    Failure::with_source(
+                        FailedError::FitFailed,
+                        &format!("base estimator {} failed to fit", b),
+                        err,
+                    )
+                })?;
+            estimators.push(estimator);
+        }
+
+        Ok(BaggingClassifier {
+            estimator_factory: self.estimator_factory,
+            estimators,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T, M, E> Classifier<M, Failure> for BaggingClassifier<T, M, E>
+where
+    T: Real,
+    M: BaseMatrix<T>,
+    E: Classifier<M, Failure>,
+{
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        if self.estimators.is_empty() {
+            return Err(Failure::predict("ensemble is not fitted"));
+        }
+        let (nrows, _) = x.shape();
+        let predictions = self
+            .estimators
+            .iter()
+            .map(|e| e.predict(x))
+            .collect::<Result<Vec<M>, Failure>>()?;
+        Ok(majority_vote(&predictions, nrows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+    use crate::linalg::dense_vector::DenseVector;
+    use crate::linear::logistic::LogisticRegression;
+    use crate::tree::decision_tree::{DecisionTreeClassifier, DecisionTreeClassifierParameters};
+    use rand::Rng;
+
+    /// Two interleaved classes on a line with 15% label noise: class 1 on `[0.25, 0.5)` and `[0.75, 1)`.
+    fn noisy_data(n: usize, seed: u64) -> (DenseMatrix<f64>, DenseMatrix<f64>) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut x = DenseMatrix::zeros(n, 1);
+        let mut y = DenseMatrix::zeros(n, 1);
+        for i in 0..n {
+            let v: f64 = rng.gen();
+            let mut label = if (v * 4.) as usize % 2 == 1 { 1. } else { 0. };
+            if rng.gen::<f64>() < 0.15 {
+                label = 1. - label;
+            }
+            x.set(i, 0, v);
+            y.set(i, 0, label);
+        }
+        (x, y)
+    }
+
+    fn tree_params() -> DecisionTreeClassifierParameters {
+        DecisionTreeClassifierParameters {
+            max_depth: Some(1),
+            ..Default::default()
+        }
+    }
+
+    /// Label 1 when at least two of three uniform features exceed 0.5, with 10% label noise. Any single
+    /// axis-aligned split is right on about 75% of the samples.
+    fn majority_data(n: usize, seed: u64) -> (DenseMatrix<f64>, DenseMatrix<f64>) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut x = DenseMatrix::zeros(n, 3);
+        let mut y = DenseMatrix::zeros(n, 1);
+        for i in 0..n {
+            let mut votes = 0;
+            for j in 0..3 {
+                let v: f64 = rng.gen();
+                x.set(i, j, v);
+                votes += (v > 0.5) as usize;
+            }
+            let mut label = if votes >= 2 { 1. } else { 0. };
+            if rng.gen::<f64>() < 0.1 {
+                label = 1. - label;
+            }
+            y.set(i, 0, label);
+        }
+        (x, y)
+    }
+
+    #[test]
+    fn bagging_beats_single_stump() {
+        let n_seeds = 20;
+        let (mut single_acc, mut bagging_acc) = (0., 0.);
+        for seed in 0..n_seeds {
+            let (x, y) = majority_data(100, 2 * seed);
+            let (x_test, y_test) = majority_data(1000, 2 * seed + 1);
+            let labels: DenseVector<f64> = y_test.get_col(0);
+
+            let single = DecisionTreeClassifier::default()
+                .fit(&x, &y, tree_params())
+                .unwrap();
+            let bagging = BaggingClassifier::new(DecisionTreeClassifier::default)
+                .fit(
+                    &x,
+                    &y,
+                    BaggingClassifierParameters {
+                        n_estimators: 50,
+                        seed,
+                        estimator_params: tree_params(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(bagging.estimators().len(), 50);
+
+            single_acc += single.score(&x_test, &labels).unwrap() / n_seeds as f64;
+            bagging_acc += bagging.score(&x_test, &labels).unwrap() / n_seeds as f64;
+        }
+        assert!(bagging_acc > single_acc + 0.02);
+    }
+
+    #[test]
+    fn reproducible_with_seed() {
+        let (x, y) = noisy_data(100, 3);
+        let fit = |seed| {
+            BaggingClassifier::new(DecisionTreeClassifier::default)
+                .fit(
+                    &x,
+                    &y,
+                    BaggingClassifierParameters {
+                        n_estimators: 5,
+                        seed,
+                        estimator_params: tree_params(),
+                    },
+                )
+                .unwrap()
+                .predict(&x)
+                .unwrap()
+        };
+        assert_eq!(fit(7), fit(7));
+    }
+
+    #[test]
+    fn base_estimator_failure() {
+        let x = DenseMatrix::from_2d_array(&[&[0.], &[1.]]);
+        let y = DenseMatrix::from_2d_array(&[&[2.], &[2.]]);
+        let err = BaggingClassifier::<f64, _, _>::new(LogisticRegression::default)
+            .fit(&x, &y, BaggingClassifierParameters::default())
+            .err()
+            .unwrap();
+        assert_eq!(err.error(), FailedError::FitFailed);
+        assert_eq!(
+            err.to_string(),
+            "Fit failed: base estimator 0 failed to fit"
+        );
+    }
+}
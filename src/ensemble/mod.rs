@@ -0,0 +1,51 @@
+//! # Ensemble Methods
+//! Estimators that combine the predictions of several base estimators fitted on resampled training data.
+
+pub mod bagging;
+pub mod random_forest;
+
+use rand::Rng;
+
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Draw `nrows` rows of `x` and `y` uniformly with replacement.
+pub(crate) fn bootstrap<T: Real, M: BaseMatrix<T>, R: Rng>(x: &M, y: &M, rng: &mut R) -> (M, M) {
+    let (nrows, ncols) = x.shape();
+    let mut x_sample = M::zeros(nrows, ncols);
+    let mut y_sample = M::zeros(nrows, 1);
+    for i in 0..nrows {
+        let k = rng.gen_range(0, nrows);
+        for j in 0..ncols {
+            x_sample.set(i, j, x.get(k, j));
+        }
+        y_sample.set(i, 0, y.get(k, 0));
+    }
+    (x_sample, y_sample)
+}
+
+/// Combine column matrices of predicted labels by majority vote. Ties go to the label first predicted for the row.
+pub(crate) fn majority_vote<T: Real, M: BaseMatrix<T>>(predictions: &[M], nrows: usize) -> M {
+    let mut labels = M::zeros(nrows, 1);
+    for i in 0..nrows {
+        // (label, votes), in order of the first estimator predicting each label
+        let mut votes: Vec<(T, usize)> = Vec::new();
+        for p in predictions {
+            let label = p.get(i, 0);
+            match votes.iter_mut().find(|(l, _)| *l == label) {
+                Some((_, count)) => *count += 1,
+                None => votes.push((label, 1)),
+            }
+        }
+        let mut best = 0;
+        for c in 1..votes.len() {
+            if votes[c].1 > votes[best].1 {
+                best = c;
+            }
+        }
+        if let Some(&(label, _)) = votes.get(best) {
+            labels.set(i, 0, label);
+        }
+    }
+    labels
+}
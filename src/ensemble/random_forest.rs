@@ -0,0 +1,240 @@
+//! # Random Forest Classifier
+//! [Random forest](https://en.wikipedia.org/wiki/Random_forest): bagged decision trees that also draw a random subset
+//! of `max_features` split candidates at every node, which decorrelates the trees. Predicts the majority label.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Classifier};
+use crate::ensemble::{bootstrap, majority_vote};
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+use crate::tree::decision_tree::{
+    DecisionTreeClassifier, DecisionTreeClassifierParameters, SplitCriterion,
+};
+
+/// Random forest parameters
+#[derive(Clone, Debug)]
+pub struct RandomForestClassifierParameters {
+    /// Number of trees.
+    pub n_estimators: usize,
+    /// Number of split candidates drawn at every node. `None` uses the square root of the number of features.
+    pub max_features: Option<usize>,
+    /// Maximum depth of every tree, `None` grows until leaves are pure.
+    pub max_depth: Option<usize>,
+    /// Impurity measure.
+    pub criterion: SplitCriterion,
+    /// Minimum number of samples a node needs to be split.
+    pub min_samples_split: usize,
+    /// Fit every tree on a bootstrap sample, otherwise on the full training data.
+    pub bootstrap: bool,
+    /// Seed of the generator drawing the bootstrap samples and the candidate features.
+    pub seed: u64,
+}
+
+impl Default for RandomForestClassifierParameters {
+    fn default() -> Self {
+        RandomForestClassifierParameters {
+            n_estimators: 100,
+            max_features: None,
+            max_depth: None,
+            criterion: SplitCriterion::Gini,
+            min_samples_split: 2,
+            bootstrap: true,
+            seed: 0,
+        }
+    }
+}
+
+/// Ensemble of randomized decision trees
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RandomForestClassifier<T: Real, M: BaseMatrix<T>> {
+    trees: Vec<DecisionTreeClassifier<T, M>>,
+    n_features: usize,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for RandomForestClassifier<T, M> {
+    fn default() -> Self {
+        RandomForestClassifier {
+            trees: Vec::new(),
+            n_features: 0,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> RandomForestClassifier<T, M> {
+    /// Fitted trees.
+    pub fn trees(&self) -> &[DecisionTreeClassifier<T, M>] {
+        &self.trees
+    }
+
+    /// Mean of the trees' [`feature_importances`](DecisionTreeClassifier::feature_importances).
+    /// Panics if the forest has not been fitted.
+    pub fn feature_importances<V: BaseVector<T>>(&self) -> V {
+        assert!(
+            !self.trees.is_empty(),
+            "feature_importances: forest is not fitted"
+        );
+        let mut importances = V::zeros(self.n_features);
+        for tree in self.trees.iter() {
+            importances.add_mut(&tree.feature_importances());
+        }
+        importances.div_scalar(T::from_usize(self.trees.len()).unwrap())
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, RandomForestClassifierParameters, Failure>
+    for RandomForestClassifier<T, M>
+{
+    fn fit(
+        self,
+        x: &M,
+        y: &M,
+        fit_params: RandomForestClassifierParameters,
+    ) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if fit_params.n_estimators == 0 {
+            return Err(Failure::fit("n_estimators must be at least 1"));
+        }
+        if nrows == 0 {
+            return Err(Failure::fit("x must have at least one sample"));
+        }
+
+        let max_features = fit_params
+            .max_features
+            .unwrap_or_else(|| ((ncols as f64).sqrt() as usize).max(1));
+        let mut rng = StdRng::seed_from_u64(fit_params.seed);
+        let mut trees = Vec::with_capacity(fit_params.n_estimators);
+        for _ in 0..fit_params.n_estimators {
+            let tree_params = DecisionTreeClassifierParameters {
+                criterion: fit_params.criterion,
+                max_depth: fit_params.max_depth,
+                min_samples_split: fit_params.min_samples_split,
+                max_features: Some(max_features),
+                seed: rng.gen(),
+            };
+            let tree = if fit_params.bootstrap {
+                let (x_sample, y_sample) = bootstrap(x, y, &mut rng);
+                DecisionTreeClassifier::default().fit(&x_sample, &y_sample, tree_params)?
+            } else {
+                DecisionTreeClassifier::default().fit(x, y, tree_params)?
+            };
+            trees.push(tree);
+        }
+
+        Ok(RandomForestClassifier {
+            trees,
+            n_features: ncols,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Classifier<M, Failure> for RandomForestClassifier<T, M> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        if self.trees.is_empty() {
+            return Err(Failure::predict("forest is not fitted"));
+        }
+        let (nrows, _) = x.shape();
+        let predictions = self
+            .trees
+            .iter()
+            .map(|tree| tree.predict(x))
+            .collect::<Result<Vec<M>, Failure>>()?;
+        Ok(majority_vote(&predictions, nrows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+    use crate::linalg::dense_vector::DenseVector;
+    use rand_distr::StandardNormal;
+
+    /// Three overlapping Gaussian blobs in the first two features, the other four features are noise.
+    fn blobs(n: usize, seed: u64) -> (DenseMatrix<f64>, DenseVector<f64>) {
+        let centers = [[0., 0.], [2., 2.], [0., 3.]];
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut x = DenseMatrix::zeros(n, 6);
+        let mut y = Vec::with_capacity(n);
+        for i in 0..n {
+            let c = i % 3;
+            for j in 0..6 {
+                let center = centers[c].get(j).copied().unwrap_or(0.);
+                let noise: f64 = rng.sample(StandardNormal);
+                x.set(i, j, center + noise);
+            }
+            y.push(c as f64);
+        }
+        (x, DenseVector::new(y))
+    }
+
+    fn column(y: &DenseVector<f64>) -> DenseMatrix<f64> {
+        DenseMatrix::new(y.len(), 1, y.to_vec())
+    }
+
+    #[test]
+    fn forest_beats_single_tree() {
+        let (x, y) = blobs(300, 1);
+        let (x_test, y_test) = blobs(600, 2);
+
+        let tree = DecisionTreeClassifier::default()
+            .fit(&x, &column(&y), Default::default())
+            .unwrap();
+        let forest = RandomForestClassifier::default()
+            .fit(
+                &x,
+                &column(&y),
+                RandomForestClassifierParameters {
+                    n_estimators: 50,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(forest.trees().len(), 50);
+        let tree_score: f64 = tree.score(&x_test, &y_test).unwrap();
+        let forest_score: f64 = forest.score(&x_test, &y_test).unwrap();
+        assert!(forest_score >= tree_score);
+
+        let importances: DenseVector<f64> = forest.feature_importances();
+        assert!((importances.sum() - 1.).abs() < 1e-12);
+        assert!(importances.get(0) > importances.get(2) && importances.get(1) > importances.get(5));
+    }
+
+    #[test]
+    fn single_tree_without_randomness_matches_plain_tree() {
+        let (x, y) = blobs(90, 3);
+        let tree = DecisionTreeClassifier::default()
+            .fit(&x, &column(&y), Default::default())
+            .unwrap();
+        let forest = RandomForestClassifier::default()
+            .fit(
+                &x,
+                &column(&y),
+                RandomForestClassifierParameters {
+                    n_estimators: 1,
+                    max_features: Some(6),
+                    bootstrap: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let (x_test, _) = blobs(200, 4);
+        assert_eq!(
+            forest.predict(&x_test).unwrap(),
+            tree.predict(&x_test).unwrap()
+        );
+        assert_eq!(forest.trees()[0].depth(), tree.depth());
+    }
+}
@@ -1,5 +1,7 @@
 use std::error::Error;
 use std::fmt;
+use std::io;
+use std::num::ParseFloatError;
 
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +9,8 @@ use serde::{Deserialize, Serialize};
 pub struct Failure {
     err: FailedError,
     msg: String,
+    #[serde(skip)]
+    source: Option<Box<dyn Error + Send + Sync>>,
 }
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
@@ -23,6 +27,10 @@ pub enum FailedError {
     DecompositionFailed,
     /// Can not solve for X
     SolutionFailed,
+    /// Invalid argument or input data
+    InvalidInput,
+    /// Can not read or write data
+    IoFailed,
 }
 
 impl Failure {
@@ -37,6 +45,7 @@ impl Failure {
         Failure {
             err: FailedError::FitFailed,
             msg: msg.to_owned(),
+            source: None,
         }
     }
 
@@ -45,6 +54,7 @@ impl Failure {
         Failure {
             err: FailedError::PredictFailed,
             msg: msg.to_owned(),
+            source: None,
         }
     }
 
@@ -53,6 +63,16 @@ impl Failure {
         Failure {
             err: FailedError::TransformFailed,
             msg: msg.to_owned(),
+            source: None,
+        }
+    }
+
+    /// new instance of `FailedError::InvalidInput`
+    pub fn invalid_input(msg: &str) -> Self {
+        Failure {
+            err: FailedError::InvalidInput,
+            msg: msg.to_owned(),
+            source: None,
         }
     }
 
@@ -61,6 +81,20 @@ impl Failure {
         Failure {
             err,
             msg: msg.to_owned(),
+            source: None,
+        }
+    }
+
+    /// new instance of `err` caused by `source`
+    pub fn with_source<E: Error + Send + Sync + 'static>(
+        err: FailedError,
+        msg: &str,
+        source: E,
+    ) -> Self {
+        Failure {
+            err,
+            msg: msg.to_owned(),
+            source: Some(Box::new(source)),
         }
     }
 }
@@ -87,6 +121,8 @@ impl fmt::Display for FailedError {
             FailedError::FindFailed => "Find failed",
             FailedError::DecompositionFailed => "Decomposition failed",
             FailedError::SolutionFailed => "Can not find solution",
+            FailedError::InvalidInput => "Invalid input",
+            FailedError::IoFailed => "IO failed",
         };
         write!(f, "{}", failed_err_str)
     }
@@ -98,4 +134,88 @@ impl fmt::Display for Failure {
     }
 }
 
-impl Error for Failure {}
+impl From<ParseFloatError> for Failure {
+    fn from(err: ParseFloatError) -> Self {
+        let msg = format!("can not parse number: {}", err);
+        Failure::with_source(FailedError::InvalidInput, &msg, err)
+    }
+}
+
+impl From<io::Error> for Failure {
+    fn from(err: io::Error) -> Self {
+        let msg = err.to_string();
+        Failure::with_source(FailedError::IoFailed, &msg, err)
+    }
+}
+
+impl From<serde_json::Error> for Failure {
+    fn from(err: serde_json::Error) -> Self {
+        if err.is_io() {
+            let msg = err.to_string();
+            Failure::with_source(FailedError::IoFailed, &msg, err)
+        } else {
+            let msg = format!("can not (de)serialize json: {}", err);
+            Failure::with_source(FailedError::InvalidInput, &msg, err)
+        }
+    }
+}
+
+impl Error for Failure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|e| e.as_ref() as &(dyn Error + 'static))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_chain() {
+        let lu = Failure::because(FailedError::DecompositionFailed, "matrix is singular");
+        let fit =
+            Failure::with_source(FailedError::FitFailed, "can not solve normal equations", lu);
+
+        let source = fit.source().unwrap();
+        assert_eq!(
+            source.to_string(),
+            "Decomposition failed: matrix is singular"
+        );
+        assert!(source.source().is_none());
+        assert!(Failure::fit("no source").source().is_none());
+    }
+
+    #[test]
+    fn serialize_skips_source() {
+        let fit = Failure::with_source(FailedError::FitFailed, "fit", Failure::predict("inner"));
+        let json = serde_json::to_string(&fit).unwrap();
+        let back: Failure = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, Failure::fit("fit"));
+        assert!(back.source().is_none());
+    }
+
+    #[test]
+    fn from_parse_float_error() {
+        fn parse(s: &str) -> Result<f64, Failure> {
+            Ok(s.parse::<f64>()?)
+        }
+
+        assert_eq!(parse("1.5").unwrap(), 1.5);
+        let err = parse("abc").unwrap_err();
+        assert_eq!(err.error(), FailedError::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "Invalid input: can not parse number: invalid float literal"
+        );
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn from_io_error() {
+        let err: Failure = io::Error::new(io::ErrorKind::NotFound, "no such file").into();
+        assert_eq!(err.error(), FailedError::IoFailed);
+        assert_eq!(err.to_string(), "IO failed: no such file");
+    }
+}
@@ -0,0 +1,4 @@
+//! # Feature Selection
+//! Transformers that keep a subset of the input columns.
+
+pub mod variance_threshold;
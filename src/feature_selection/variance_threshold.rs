@@ -0,0 +1,159 @@
+//! # Variance Threshold
+//! Drops columns whose variance on the training data does not exceed a threshold, by default the constant columns.
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Transformer};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Variance threshold parameters
+#[derive(Clone, Debug)]
+pub struct VarianceThresholdParameters<T: Real> {
+    /// Columns with a variance less than or equal to this value are dropped.
+    pub threshold: T,
+}
+
+impl<T: Real> Default for VarianceThresholdParameters<T> {
+    fn default() -> Self {
+        VarianceThresholdParameters {
+            threshold: T::zero(),
+        }
+    }
+}
+
+/// Feature selector that removes low-variance columns
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VarianceThreshold<T: Real, M: BaseMatrix<T>> {
+    variances: Vec<T>,
+    support: Vec<usize>,
+    _phantom: PhantomData<M>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for VarianceThreshold<T, M> {
+    fn default() -> Self {
+        VarianceThreshold {
+            variances: Vec::new(),
+            support: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> VarianceThreshold<T, M> {
+    /// Per-column variance seen during `fit`.
+    pub fn variances(&self) -> &[T] {
+        &self.variances
+    }
+
+    /// Indices of the retained columns, in ascending order.
+    pub fn get_support(&self) -> Vec<usize> {
+        self.support.clone()
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, VarianceThresholdParameters<T>, Failure>
+    for VarianceThreshold<T, M>
+{
+    /// Compute the variance of every column of `x`, `y` is ignored.
+    fn fit(
+        self,
+        x: &M,
+        _y: &M,
+        fit_params: VarianceThresholdParameters<T>,
+    ) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        if nrows == 0 {
+            return Err(Failure::fit("x must have at least one row"));
+        }
+        if fit_params.threshold < T::zero() {
+            return Err(Failure::fit(&format!(
+                "threshold must be non-negative, got {}",
+                fit_params.threshold
+            )));
+        }
+
+        let n = T::from_usize(nrows).unwrap();
+        let mut variances = Vec::with_capacity(ncols);
+        for j in 0..ncols {
+            let (min, max) = (0..nrows).fold((T::infinity(), T::neg_infinity()), |(lo, hi), i| {
+                (lo.min(x.get(i, j)), hi.max(x.get(i, j)))
+            });
+            // The rounded mean of a constant column can leave a tiny positive variance, report it as exactly zero.
+            if min == max {
+                variances.push(T::zero());
+                continue;
+            }
+            let mean = (0..nrows).map(|i| x.get(i, j)).sum::<T>() / n;
+            let var = (0..nrows).map(|i| (x.get(i, j) - mean).square()).sum::<T>() / n;
+            variances.push(var);
+        }
+        let support = (0..ncols)
+            .filter(|&j| variances[j] > fit_params.threshold)
+            .collect();
+
+        Ok(VarianceThreshold {
+            variances,
+            support,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Transformer<M, Failure> for VarianceThreshold<T, M> {
+    fn transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = x.shape();
+        if ncols != self.variances.len() {
+            return Err(Failure::transform(&format!(
+                "x has {} features, selector was fitted with {}",
+                ncols,
+                self.variances.len()
+            )));
+        }
+        let mut r = M::zeros(nrows, self.support.len());
+        for i in 0..nrows {
+            for (k, &j) in self.support.iter().enumerate() {
+                r.set(i, k, x.get(i, j));
+            }
+        }
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+
+    #[test]
+    fn drops_constant_column() {
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 0.1, 5.],
+            &[2., 0.1, 5.1],
+            &[3., 0.1, 5.],
+            &[4., 0.1, 5.1],
+        ]);
+        let selector = VarianceThreshold::default()
+            .fit(&x, &x, Default::default())
+            .unwrap();
+        assert_eq!(selector.get_support(), vec![0, 2]);
+        assert_eq!(
+            selector.transform(&x).unwrap(),
+            DenseMatrix::from_2d_array(&[&[1., 5.], &[2., 5.1], &[3., 5.], &[4., 5.1]])
+        );
+
+        let selector = VarianceThreshold::default()
+            .fit(&x, &x, VarianceThresholdParameters { threshold: 0.01 })
+            .unwrap();
+        assert_eq!(selector.get_support(), vec![0]);
+
+        let x = DenseMatrix::from_2d_array(&[&[1., 0.1], &[2., 0.1], &[3., 0.1]]);
+        let selector = VarianceThreshold::default()
+            .fit(&x, &x, Default::default())
+            .unwrap();
+        assert_eq!(selector.get_support(), vec![0]);
+    }
+}
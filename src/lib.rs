@@ -1,4 +1,19 @@
 pub mod base;
-mod error;
-mod numbers;
-mod linalg;
+pub mod cluster;
+#[cfg(feature = "datasets")]
+pub mod dataset;
+pub mod decomposition;
+pub mod ensemble;
+pub mod error;
+pub mod feature_selection;
+pub mod linalg;
+pub mod linear;
+pub mod metrics;
+pub mod model_selection;
+pub mod naive_bayes;
+pub mod neighbors;
+pub mod numbers;
+pub mod persistence;
+pub mod pipeline;
+pub mod preprocessing;
+pub mod tree;
@@ -0,0 +1,119 @@
+//! # Cholesky Decomposition
+//! Factorizes a symmetric positive-definite matrix \\( A \\) as \\( A = LL^T \\), where \\( L \\) is lower triangular.
+//! Roughly twice as fast as LU for solving systems such as \\( (X^TX + \alpha I)w = X^Ty \\).
+
+use std::marker::PhantomData;
+
+use crate::error::{FailedError, Failure};
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// Result of the Cholesky decomposition
+#[derive(Clone, Debug)]
+pub struct Cholesky<T: Real, M: BaseMatrix<T>> {
+    l: M,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Cholesky<T, M> {
+    /// Decompose `a`, failing if the matrix is not square or not positive definite.
+    /// Only the lower triangle of `a` is read.
+    pub fn new(a: &M) -> Result<Self, Failure> {
+        let (m, n) = a.shape();
+        if m != n {
+            return Err(Failure::because(
+                FailedError::DecompositionFailed,
+                &format!("Cholesky requires a square matrix, got {}x{}", m, n),
+            ));
+        }
+
+        let mut l = M::zeros(n, n);
+        for j in 0..n {
+            let mut d = a.get(j, j);
+            for k in 0..j {
+                d -= l.get(j, k) * l.get(j, k);
+            }
+            if d <= T::zero() {
+                return Err(Failure::because(
+                    FailedError::DecompositionFailed,
+                    &format!("matrix is not positive definite at pivot {}", j),
+                ));
+            }
+            let d = d.sqrt();
+            l.set(j, j, d);
+            for i in (j + 1)..n {
+                let mut s = a.get(i, j);
+                for k in 0..j {
+                    s -= l.get(i, k) * l.get(j, k);
+                }
+                l.set(i, j, s / d);
+            }
+        }
+
+        Ok(Cholesky {
+            l,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Lower triangular factor `L`.
+    pub fn l(&self) -> M {
+        self.l.clone()
+    }
+
+    /// Solve `A·x = b` for `x`.
+    pub fn solve<V: BaseVector<T>>(&self, b: &V) -> Result<V, Failure> {
+        let (n, _) = self.l.shape();
+        if b.len() != n {
+            return Err(Failure::because(
+                FailedError::SolutionFailed,
+                &format!("b has {} elements, expected {}", b.len(), n),
+            ));
+        }
+
+        let y = self.l.solve_triangular(b, false, false)?;
+        self.l.transpose().solve_triangular(&y, true, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconstruct() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[4., 12., -16.], &[12., 37., -43.], &[-16., -43., 98.]]);
+        let l = a.cholesky().unwrap().l();
+
+        assert_eq!(
+            l,
+            vec![vec![2., 0., 0.], vec![6., 1., 0.], vec![-8., 5., 3.]]
+        );
+        let llt = l.matmul(&l.transpose());
+        for i in 0..3 {
+            assert!(llt[i].approximate_eq(&a[i], 1e-10));
+        }
+    }
+
+    #[test]
+    fn solve() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[4., 12., -16.], &[12., 37., -43.], &[-16., -43., 98.]]);
+        let b = vec![0., 6., 39.];
+        let x = a.cholesky().unwrap().solve(&b).unwrap();
+        assert!(x.approximate_eq(&vec![1., 1., 1.], 1e-10));
+    }
+
+    #[test]
+    fn not_positive_definite() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[2., 1.]]);
+        assert_eq!(
+            a.cholesky().unwrap_err(),
+            Failure::because(
+                FailedError::DecompositionFailed,
+                "matrix is not positive definite at pivot 1"
+            )
+        );
+    }
+}
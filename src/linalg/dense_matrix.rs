@@ -0,0 +1,174 @@
+//! # Dense Matrix
+//! Row-major, heap-allocated implementation of [`BaseMatrix`].
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Matrix that stores its elements row by row in a single `Vec<T>`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DenseMatrix<T> {
+    values: Vec<T>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl<T: Real> DenseMatrix<T> {
+    /// Wrap row-major `values` as a `nrows x ncols` matrix.
+    /// Panics if `values.len() != nrows * ncols`.
+    pub fn new(nrows: usize, ncols: usize, values: Vec<T>) -> Self {
+        assert!(
+            values.len() == nrows * ncols,
+            "DenseMatrix::new: {} values do not fit a {}x{} matrix",
+            values.len(),
+            nrows,
+            ncols
+        );
+        DenseMatrix {
+            values,
+            nrows,
+            ncols,
+        }
+    }
+
+    /// Elements in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    fn check_bounds(&self, op: &str, row: usize, col: usize) {
+        assert!(
+            row < self.nrows && col < self.ncols,
+            "{}: index ({}, {}) out of bounds for {}x{} matrix",
+            op,
+            row,
+            col,
+            self.nrows,
+            self.ncols
+        );
+    }
+}
+
+impl<T: Real> BaseMatrix<T> for DenseMatrix<T> {
+    fn get(&self, row: usize, col: usize) -> T {
+        self.check_bounds("get", row, col);
+        self.values[row * self.ncols + col]
+    }
+
+    fn set(&mut self, row: usize, col: usize, x: T) {
+        self.check_bounds("set", row, col);
+        self.values[row * self.ncols + col] = x;
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    fn zeros(nrows: usize, ncols: usize) -> Self {
+        DenseMatrix::new(nrows, ncols, vec![T::zero(); nrows * ncols])
+    }
+
+    fn ones(nrows: usize, ncols: usize) -> Self {
+        DenseMatrix::new(nrows, ncols, vec![T::one(); nrows * ncols])
+    }
+
+    fn from_2d_array(values: &[&[T]]) -> Self {
+        let nrows = values.len();
+        let ncols = if nrows > 0 { values[0].len() } else { 0 };
+        let mut flat = Vec::with_capacity(nrows * ncols);
+        for (r, row) in values.iter().enumerate() {
+            assert!(
+                row.len() == ncols,
+                "from_2d_array: row {} has {} elements, expected {}",
+                r,
+                row.len(),
+                ncols
+            );
+            flat.extend_from_slice(row);
+        }
+        DenseMatrix::new(nrows, ncols, flat)
+    }
+
+    fn transpose(&self) -> Self {
+        let mut values = Vec::with_capacity(self.values.len());
+        for c in 0..self.ncols {
+            for r in 0..self.nrows {
+                values.push(self.values[r * self.ncols + c]);
+            }
+        }
+        DenseMatrix::new(self.ncols, self.nrows, values)
+    }
+}
+
+/// Prints one row per line with right-aligned columns.
+impl<T: Real> fmt::Display for DenseMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<String> = self.values.iter().map(|x| x.to_string()).collect();
+        let mut widths = vec![0; self.ncols];
+        for (i, cell) in cells.iter().enumerate() {
+            widths[i % self.ncols] = widths[i % self.ncols].max(cell.len());
+        }
+        for r in 0..self.nrows {
+            write!(f, "[")?;
+            for c in 0..self.ncols {
+                if c > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(
+                    f,
+                    "{:>width$}",
+                    cells[r * self.ncols + c],
+                    width = widths[c]
+                )?;
+            }
+            writeln!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_vector::DenseVector;
+
+    #[test]
+    fn layout() {
+        let a = DenseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        assert_eq!(a.shape(), (2, 3));
+        assert_eq!(a.as_slice(), &[1., 2., 3., 4., 5., 6.]);
+
+        let t = a.transpose();
+        assert_eq!(t.shape(), (3, 2));
+        assert_eq!(t.as_slice(), &[1., 4., 2., 5., 3., 6.]);
+
+        let aat = a.matmul(&t);
+        assert_eq!(aat, DenseMatrix::from_2d_array(&[&[14., 32.], &[32., 77.]]));
+
+        let row: DenseVector<f64> = a.get_row(1);
+        let col: DenseVector<f64> = a.get_col(2);
+        assert_eq!(row, DenseVector::new(vec![4., 5., 6.]));
+        assert_eq!(col, DenseVector::new(vec![3., 6.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "get: index (2, 0) out of bounds for 2x3 matrix")]
+    fn get_out_of_bounds() {
+        DenseMatrix::<f64>::zeros(2, 3).get(2, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "DenseMatrix::new: 5 values do not fit a 2x3 matrix")]
+    fn new_wrong_length() {
+        DenseMatrix::new(2, 3, vec![0f64; 5]);
+    }
+
+    #[test]
+    fn display() {
+        let a = DenseMatrix::from_2d_array(&[&[1., -20.], &[300., 4.5]]);
+        assert_eq!(format!("{}", a), "[  1, -20]\n[300, 4.5]\n");
+    }
+}
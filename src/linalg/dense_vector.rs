@@ -0,0 +1,286 @@
+//! # Dense Vector
+//! Contiguous, heap-allocated implementation of [`BaseVector`].
+
+use serde::{Deserialize, Serialize};
+
+use std::iter::FromIterator;
+
+use crate::linalg::dot::dot_slice;
+use crate::linalg::BaseVector;
+use crate::numbers::Real;
+
+/// Vector that stores its elements in a `Vec<T>`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DenseVector<T>(Vec<T>);
+
+impl<T: Real> DenseVector<T> {
+    /// Wrap `values` without copying.
+    pub fn new(values: Vec<T>) -> Self {
+        DenseVector(values)
+    }
+
+    /// Elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Consume the vector and return the underlying storage.
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    fn check_lengths(&self, other: &Self, op: &str) {
+        assert!(
+            self.0.len() == other.0.len(),
+            "{}: length mismatch (a={}, b={})",
+            op,
+            self.0.len(),
+            other.0.len()
+        );
+    }
+}
+
+impl<T: Real> FromIterator<T> for DenseVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        DenseVector(iter.into_iter().collect())
+    }
+}
+
+impl<T: Real> IntoIterator for DenseVector<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: Real> BaseVector<T> for DenseVector<T> {
+    fn get(&self, i: usize) -> T {
+        self.0[i]
+    }
+
+    fn set(&mut self, i: usize, x: T) {
+        self.0[i] = x
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn from_array(arr: &[T]) -> Self {
+        DenseVector(arr.to_vec())
+    }
+
+    fn to_vec(&self) -> Vec<T> {
+        self.0.clone()
+    }
+
+    fn zeros(len: usize) -> Self {
+        DenseVector(vec![T::zero(); len])
+    }
+
+    fn ones(len: usize) -> Self {
+        DenseVector(vec![T::one(); len])
+    }
+
+    fn fill(len: usize, value: T) -> Self {
+        DenseVector(vec![value; len])
+    }
+
+    fn dot(&self, other: &Self) -> T {
+        dot_slice(&self.0, &other.0)
+    }
+
+    fn approximate_eq(&self, other: &Self, eps: T) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .all(|(&a, &b)| (a - b).abs() <= eps)
+    }
+
+    fn norm2(&self) -> T {
+        self.0.iter().map(|&x| x * x).sum::<T>().sqrt()
+    }
+
+    fn div_element_mut(&mut self, pos: usize, x: T) {
+        self.0[pos] /= x;
+    }
+
+    fn mul_element_mut(&mut self, pos: usize, x: T) {
+        self.0[pos] *= x;
+    }
+
+    fn add_element_mut(&mut self, pos: usize, x: T) {
+        self.0[pos] += x;
+    }
+
+    fn sub_element_mut(&mut self, pos: usize, x: T) {
+        self.0[pos] -= x;
+    }
+
+    fn add_mut(&mut self, other: &Self) -> &Self {
+        self.check_lengths(other, "add_mut");
+        for (a, &b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a += b;
+        }
+        self
+    }
+
+    fn sub_mut(&mut self, other: &Self) -> &Self {
+        self.check_lengths(other, "sub_mut");
+        for (a, &b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a -= b;
+        }
+        self
+    }
+
+    fn mul_mut(&mut self, other: &Self) -> &Self {
+        self.check_lengths(other, "mul_mut");
+        for (a, &b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a *= b;
+        }
+        self
+    }
+
+    fn div_mut(&mut self, other: &Self) -> &Self {
+        self.check_lengths(other, "div_mut");
+        for (a, &b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a /= b;
+        }
+        self
+    }
+
+    fn sum(&self) -> T {
+        self.0.iter().copied().sum()
+    }
+
+    /// Unique values in order of first appearance. Values are compared bitwise, so NaN is kept once and `0.0` and
+    /// `-0.0` are distinct.
+    fn unique(&self) -> Vec<T> {
+        let mut result: Vec<T> = Vec::new();
+        for &x in self.0.iter() {
+            if !result.iter().any(|r| r.to_bits() == x.to_bits()) {
+                result.push(x);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(values: &[f64]) -> DenseVector<f64> {
+        DenseVector::from_array(values)
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(DenseVector::<f64>::zeros(3).to_vec(), vec![0., 0., 0.]);
+        assert_eq!(DenseVector::<f64>::ones(2).to_vec(), vec![1., 1.]);
+        assert_eq!(DenseVector::fill(2, 7.).to_vec(), vec![7., 7.]);
+        assert_eq!(DenseVector::new(vec![1., 2.]), v(&[1., 2.]));
+        assert_eq!(v(&[1., 2.]).into_vec(), vec![1., 2.]);
+        assert!(DenseVector::<f64>::zeros(0).is_empty());
+    }
+
+    #[test]
+    fn collect_and_iterate() {
+        let a: DenseVector<f64> = [1., 2., 3.].iter().map(|x| x * 2.).collect();
+        assert_eq!(a, v(&[2., 4., 6.]));
+        assert_eq!(a.into_iter().collect::<Vec<f64>>(), vec![2., 4., 6.]);
+
+        let mut sum = 0.;
+        for x in v(&[0.5, 1.5]) {
+            sum += x;
+        }
+        assert_eq!(sum, 2.);
+    }
+
+    #[test]
+    fn get_set() {
+        let mut a = v(&[1., 2., 3.]);
+        a.set(1, 5.);
+        assert_eq!(a.get(1), 5.);
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.as_slice(), &[1., 5., 3.]);
+    }
+
+    #[test]
+    fn dot_and_norms() {
+        let a = v(&[3., -4.]);
+        assert_eq!(a.dot(&v(&[2., 1.])), 2.);
+        assert_eq!(a.norm2(), 5.);
+        assert_eq!(a.l1_norm(), 7.);
+        assert_eq!(a.norm(f64::INFINITY), 4.);
+        assert_eq!(a.norm(f64::NEG_INFINITY), 3.);
+        assert!((a.norm(3.) - 91f64.powf(1. / 3.)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "dot: length mismatch (a=2, b=1)")]
+    fn dot_length_mismatch() {
+        v(&[1., 2.]).dot(&v(&[1.]));
+    }
+
+    #[test]
+    fn element_ops() {
+        let mut a = v(&[1., 2., 3., 4.]);
+        a.add_element_mut(0, 1.);
+        a.sub_element_mut(1, 1.);
+        a.mul_element_mut(2, 2.);
+        a.div_element_mut(3, 2.);
+        assert_eq!(a, v(&[2., 1., 6., 2.]));
+    }
+
+    #[test]
+    fn vector_ops() {
+        let a = v(&[1., 2., 3.]);
+        let b = v(&[2., 4., 6.]);
+        assert_eq!(a.add(&b), v(&[3., 6., 9.]));
+        assert_eq!(a.sub(&b), v(&[-1., -2., -3.]));
+        assert_eq!(a.mul(&b), v(&[2., 8., 18.]));
+        assert_eq!(b.div(&a), v(&[2., 2., 2.]));
+        assert_eq!(a.add_scalar(1.), v(&[2., 3., 4.]));
+        assert_eq!(a.mul_scalar(2.), b);
+        assert!(a.approximate_eq(&v(&[1., 2., 3. + 1e-9]), 1e-8));
+        assert!(!a.approximate_eq(&v(&[1., 2.]), 1e-8));
+    }
+
+    #[test]
+    #[should_panic(expected = "add_mut: length mismatch (a=2, b=3)")]
+    fn vector_ops_length_mismatch() {
+        v(&[1., 2.]).add_mut(&v(&[1., 2., 3.]));
+    }
+
+    #[test]
+    fn statistics() {
+        let a = v(&[2., 4., 4., 4., 5., 5., 7., 9.]);
+        assert_eq!(a.sum(), 40.);
+        assert_eq!(a.mean(), 5.);
+        assert_eq!(a.var(), 4.);
+        assert_eq!(a.std(), 2.);
+        assert_eq!(a.median(), 4.5);
+        assert_eq!(a.argmax(), 7);
+        assert_eq!(a.argmin(), 0);
+        assert_eq!(BaseVector::max(&a), 9.);
+        assert_eq!(BaseVector::min(&a), 2.);
+        assert_eq!(a.cumsum().get(7), 40.);
+    }
+
+    #[test]
+    fn unique_preserves_order() {
+        let a = v(&[3., 1., 3., f64::NAN, 2., 1., f64::NAN, -0., 0.]);
+        let u = a.unique();
+        assert_eq!(u.len(), 6);
+        assert_eq!(&u[..2], &[3., 1.]);
+        assert!(u[2].is_nan());
+        assert_eq!(u[3], 2.);
+        assert!(u[4] == 0. && u[4].is_sign_negative());
+        assert!(u[5] == 0. && u[5].is_sign_positive());
+    }
+}
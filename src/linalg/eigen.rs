@@ -0,0 +1,188 @@
+//! # Symmetric Eigenvalue Decomposition
+//! Factorizes a symmetric matrix \\( A \\) as \\( A = V \Lambda V^T \\) using the cyclic
+//! [Jacobi eigenvalue algorithm](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm), where the columns of
+//! \\( V \\) are orthonormal eigenvectors and \\( \Lambda \\) holds the eigenvalues.
+
+use crate::error::{FailedError, Failure};
+use crate::linalg::{cmp_nan_last, BaseMatrix};
+use crate::numbers::Real;
+
+/// Upper bound on Jacobi sweeps. Convergence is quadratic, so well-conditioned inputs need well under ten.
+const MAX_SWEEPS: usize = 100;
+
+/// Eigenvalues, sorted descending, and matching eigenvectors as columns of a symmetric matrix.
+/// Fails if `a` is not square, contains NaN or infinite values, is not symmetric within `epsilon * max|a_ij| * n`,
+/// or the off-diagonal mass does not fall below `epsilon^2 * ||a||_F^2` within `MAX_SWEEPS` sweeps.
+pub(crate) fn symmetric_eigen<T: Real, M: BaseMatrix<T>>(a: &M) -> Result<(Vec<T>, M), Failure> {
+    let (m, n) = a.shape();
+    if m != n {
+        return Err(Failure::because(
+            FailedError::DecompositionFailed,
+            &format!("eigh requires a square matrix, got {}x{}", m, n),
+        ));
+    }
+
+    if !a.all_finite() {
+        return Err(Failure::because(
+            FailedError::DecompositionFailed,
+            "eigh requires finite values, got NaN or infinity",
+        ));
+    }
+
+    let mut max_abs = T::zero();
+    let mut frobenius_sq = T::zero();
+    for i in 0..n {
+        for j in 0..n {
+            max_abs = max_abs.max(a.get(i, j).abs());
+            frobenius_sq += a.get(i, j).square();
+        }
+    }
+    let tol = T::epsilon() * max_abs * T::from_usize(n).unwrap();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if (a.get(i, j) - a.get(j, i)).abs() > tol {
+                return Err(Failure::because(
+                    FailedError::DecompositionFailed,
+                    &format!("matrix is not symmetric at ({}, {})", i, j),
+                ));
+            }
+        }
+    }
+
+    let mut a = a.clone();
+    let mut v = M::eye(n);
+
+    // Rotations preserve the Frobenius norm, so the threshold is fixed up front. A NaN from overflow never
+    // satisfies it and ends in the sweep limit error rather than in garbage eigenpairs.
+    let threshold = T::epsilon() * T::epsilon() * frobenius_sq;
+    for sweep in 0..=MAX_SWEEPS {
+        let mut off = T::zero();
+        for p in 0..n {
+            for q in (p + 1)..n {
+                off += a.get(p, q).square();
+            }
+        }
+        if off <= threshold {
+            break;
+        }
+        if sweep == MAX_SWEEPS {
+            return Err(Failure::because(
+                FailedError::DecompositionFailed,
+                &format!(
+                    "Jacobi eigenvalue iteration did not converge in {} sweeps",
+                    MAX_SWEEPS
+                ),
+            ));
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let apq = a.get(p, q);
+                if apq == T::zero() {
+                    continue;
+                }
+                let theta = (a.get(q, q) - a.get(p, p)) / (T::two() * apq);
+                let t = theta.sign_nonzero() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let akp = a.get(k, p);
+                    let akq = a.get(k, q);
+                    a.set(k, p, c * akp - s * akq);
+                    a.set(k, q, s * akp + c * akq);
+                }
+                for k in 0..n {
+                    let apk = a.get(p, k);
+                    let aqk = a.get(q, k);
+                    a.set(p, k, c * apk - s * aqk);
+                    a.set(q, k, s * apk + c * aqk);
+                }
+                for k in 0..n {
+                    let vkp = v.get(k, p);
+                    let vkq = v.get(k, q);
+                    v.set(k, p, c * vkp - s * vkq);
+                    v.set(k, q, s * vkp + c * vkq);
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| cmp_nan_last(&a.get(j, j), &a.get(i, i)));
+    let eigenvalues = order.iter().map(|&i| a.get(i, i)).collect();
+    let mut vectors = M::zeros(n, n);
+    for (c, &i) in order.iter().enumerate() {
+        for k in 0..n {
+            vectors.set(k, c, v.get(k, i));
+        }
+    }
+    Ok((eigenvalues, vectors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::BaseVector;
+
+    #[test]
+    fn diagonal() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[2., 0., 0.], &[0., 5., 0.], &[0., 0., -1.]]);
+        let (values, vectors): (Vec<f64>, _) = a.eigh().unwrap();
+
+        assert_eq!(values, vec![5., 2., -1.]);
+        assert_eq!(
+            vectors,
+            vec![vec![0., 1., 0.], vec![1., 0., 0.], vec![0., 0., 1.]]
+        );
+    }
+
+    #[test]
+    fn symmetric() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[4., 1., 2.], &[1., 3., 0.], &[2., 0., 5.]]);
+        let (values, vectors): (Vec<f64>, _) = a.eigh().unwrap();
+
+        for (c, &value) in values.iter().enumerate() {
+            let v: Vec<f64> = vectors.get_col(c);
+            let av: Vec<f64> = (0..3)
+                .map(|i| (0..3).map(|j| a.get(i, j) * v[j]).sum())
+                .collect();
+            assert!(av.approximate_eq(&v.mul_scalar(value), 1e-10));
+        }
+        assert!(values[0] >= values[1] && values[1] >= values[2]);
+    }
+
+    #[test]
+    fn not_symmetric() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[0., 1.]]);
+        assert_eq!(
+            a.eigh::<Vec<f64>>().unwrap_err(),
+            Failure::because(
+                FailedError::DecompositionFailed,
+                "matrix is not symmetric at (0, 1)"
+            )
+        );
+    }
+
+    #[test]
+    fn non_finite() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., f64::NAN], &[f64::NAN, 1.]]);
+        assert_eq!(
+            a.eigh::<Vec<f64>>().unwrap_err(),
+            Failure::because(
+                FailedError::DecompositionFailed,
+                "eigh requires finite values, got NaN or infinity"
+            )
+        );
+    }
+
+    #[test]
+    fn large_scale_converges() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[4e12, 1e12, 2e12], &[1e12, 3e12, 0.], &[2e12, 0., 5e12]]);
+        let (values, _): (Vec<f64>, _) = a.eigh().unwrap();
+        let trace: f64 = values.iter().sum();
+        assert!((trace - 12e12).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,150 @@
+//! # LU Decomposition
+//! Factorizes a square matrix \\( A \\) as \\( PA = LU \\) with partial pivoting, where \\( L \\) is unit lower triangular
+//! and \\( U \\) is upper triangular. Used to solve linear systems and compute determinants.
+
+use crate::error::{FailedError, Failure};
+use crate::linalg::dense_vector::DenseVector;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// Result of the LU decomposition
+#[derive(Clone, Debug)]
+pub struct LU<T: Real, M: BaseMatrix<T>> {
+    lu: M,
+    pivot: Vec<usize>,
+    pivot_sign: T,
+}
+
+impl<T: Real, M: BaseMatrix<T>> LU<T, M> {
+    /// Decompose `a`, failing if the matrix is not square or is singular.
+    pub fn new(a: &M) -> Result<Self, Failure> {
+        let (m, n) = a.shape();
+        if m != n {
+            return Err(Failure::because(
+                FailedError::DecompositionFailed,
+                &format!("LU requires a square matrix, got {}x{}", m, n),
+            ));
+        }
+
+        let mut lu = a.clone();
+        let mut pivot: Vec<usize> = (0..n).collect();
+        let mut pivot_sign = T::one();
+
+        let mut max_abs = T::zero();
+        for i in 0..n {
+            for j in 0..n {
+                max_abs = max_abs.max(lu.get(i, j).abs());
+            }
+        }
+        let tol = T::epsilon() * max_abs * T::from_usize(n).unwrap();
+
+        for k in 0..n {
+            let mut p = k;
+            for i in (k + 1)..n {
+                if lu.get(i, k).abs() > lu.get(p, k).abs() {
+                    p = i;
+                }
+            }
+            if lu.get(p, k).abs() <= tol {
+                return Err(Failure::because(
+                    FailedError::DecompositionFailed,
+                    &format!("matrix is singular at pivot {}", k),
+                ));
+            }
+            if p != k {
+                lu.swap_rows(k, p);
+                pivot.swap(k, p);
+                pivot_sign = -pivot_sign;
+            }
+            for i in (k + 1)..n {
+                let f = lu.get(i, k) / lu.get(k, k);
+                lu.set(i, k, f);
+                for j in (k + 1)..n {
+                    lu.set(i, j, lu.get(i, j) - f * lu.get(k, j));
+                }
+            }
+        }
+
+        Ok(LU {
+            lu,
+            pivot,
+            pivot_sign,
+        })
+    }
+
+    /// Solve `A·x = b` for `x`.
+    pub fn solve<V: BaseVector<T>>(&self, b: &V) -> Result<V, Failure> {
+        let (n, _) = self.lu.shape();
+        if b.len() != n {
+            return Err(Failure::because(
+                FailedError::SolutionFailed,
+                &format!("b has {} elements, expected {}", b.len(), n),
+            ));
+        }
+
+        let pb: Vec<T> = self.pivot.iter().map(|&p| b.get(p)).collect();
+        let y = self.lu.solve_triangular(&V::from_array(&pb), false, true)?;
+        self.lu.solve_triangular(&y, true, false)
+    }
+
+    /// Inverse of the decomposed matrix, obtained by solving `A·X = I` column by column.
+    pub fn inverse(&self) -> Result<M, Failure> {
+        let (n, _) = self.lu.shape();
+        let mut inv = M::zeros(n, n);
+        for j in 0..n {
+            let mut e = DenseVector::zeros(n);
+            e.set(j, T::one());
+            let x = self.solve(&e)?;
+            for i in 0..n {
+                inv.set(i, j, x.get(i));
+            }
+        }
+        Ok(inv)
+    }
+
+    /// Determinant of the decomposed matrix.
+    pub fn det(&self) -> T {
+        let (n, _) = self.lu.shape();
+        let mut det = self.pivot_sign;
+        for i in 0..n {
+            det *= self.lu.get(i, i);
+        }
+        det
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[2., 1., 1.], &[4., -6., 0.], &[-2., 7., 2.]]);
+        let b = vec![5., -2., 9.];
+
+        let x = a.lu().unwrap().solve(&b).unwrap();
+        let ax: Vec<f64> = (0..3)
+            .map(|i| (0..3).map(|j| a.get(i, j) * x[j]).sum())
+            .collect();
+
+        assert!(ax.approximate_eq(&b, 1e-10));
+        assert!(x.approximate_eq(&vec![1., 1., 2.], 1e-10));
+    }
+
+    #[test]
+    fn det() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[2., 1., 1.], &[4., -6., 0.], &[-2., 7., 2.]]);
+        assert!((a.lu().unwrap().det() + 16.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn singular() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(
+            a.lu().unwrap_err().error(),
+            FailedError::DecompositionFailed
+        );
+    }
+}
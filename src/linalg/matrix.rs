@@ -0,0 +1,1007 @@
+//! # Matrix
+//! Generic two-dimensional array of real numbers, the input type expected by most estimators.
+//!
+//! Enable the `parallel` Cargo feature to compute [`BaseMatrix::matmul`] with [rayon](https://docs.rs/rayon).
+//! The serial implementation is the default.
+
+use crate::error::{FailedError, Failure};
+use crate::linalg::cholesky::Cholesky;
+use crate::linalg::eigen::symmetric_eigen;
+use crate::linalg::lu::LU;
+use crate::linalg::qr::QR;
+use crate::linalg::svd::SVD;
+use crate::linalg::BaseVector;
+use crate::numbers::Real;
+use std::fmt::Debug;
+
+/// Two-dimensional array of real numbers
+pub trait BaseMatrix<T: Real>: Clone + Debug {
+    /// Get an element of the matrix
+    /// * `row` - row index
+    /// * `col` - column index
+    fn get(&self, row: usize, col: usize) -> T;
+
+    /// Set an element at `row`, `col` to `x`
+    /// * `row` - row index
+    /// * `col` - column index
+    /// * `x` - new value
+    fn set(&mut self, row: usize, col: usize, x: T);
+
+    /// Get shape of the matrix as a `(rows, cols)` tuple
+    fn shape(&self) -> (usize, usize);
+
+    /// Create new matrix of zeros with `nrows` rows and `ncols` columns.
+    fn zeros(nrows: usize, ncols: usize) -> Self;
+
+    /// Create new matrix of ones with `nrows` rows and `ncols` columns.
+    fn ones(nrows: usize, ncols: usize) -> Self;
+
+    /// Create a new matrix from a 2d array, where each inner array is a row.
+    fn from_2d_array(values: &[&[T]]) -> Self {
+        let nrows = values.len();
+        let ncols = if nrows > 0 { values[0].len() } else { 0 };
+        let mut m = Self::zeros(nrows, ncols);
+        for (r, row) in values.iter().enumerate() {
+            assert!(
+                row.len() == ncols,
+                "from_2d_array: row {} has {} elements, expected {}",
+                r,
+                row.len(),
+                ncols
+            );
+            for (c, elem) in row.iter().enumerate() {
+                m.set(r, c, *elem);
+            }
+        }
+        m
+    }
+
+    /// Matrix product of two matrices
+    ///
+    /// With the `parallel` feature enabled, output rows are computed on the rayon thread pool.
+    fn matmul(&self, other: &Self) -> Self {
+        let (nrows, ncols) = self.shape();
+        let (other_nrows, other_ncols) = other.shape();
+        assert!(
+            ncols == other_nrows,
+            "matmul: shape mismatch ({}x{} and {}x{})",
+            nrows,
+            ncols,
+            other_nrows,
+            other_ncols
+        );
+        #[cfg(feature = "parallel")]
+        return matmul_parallel(self, other);
+        #[cfg(not(feature = "parallel"))]
+        return matmul_serial(self, other);
+    }
+
+    /// Product of the matrix with the vector `v`, of length `nrows`. Avoids building the column matrix that
+    /// [`matmul`](BaseMatrix::matmul) would need. Panics if `v.len() != ncols`.
+    fn matvec<V: BaseVector<T>>(&self, v: &V) -> V {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            v.len() == ncols,
+            "matvec: shape mismatch ({}x{} matrix and vector of length {})",
+            nrows,
+            ncols,
+            v.len()
+        );
+        let mut r = V::zeros(nrows);
+        for i in 0..nrows {
+            let mut s = T::zero();
+            for j in 0..ncols {
+                s += self.get(i, j) * v.get(j);
+            }
+            r.set(i, s);
+        }
+        r
+    }
+
+    /// Return the transposed matrix of shape `(cols, rows)`.
+    fn transpose(&self) -> Self {
+        let (nrows, ncols) = self.shape();
+        let mut r = Self::zeros(ncols, nrows);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                r.set(j, i, self.get(i, j));
+            }
+        }
+        r
+    }
+
+    /// Transpose a square matrix in place. Panics if the matrix is not square.
+    fn transpose_mut(&mut self) {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            nrows == ncols,
+            "transpose_mut: matrix must be square, got {}x{}",
+            nrows,
+            ncols
+        );
+        for i in 0..nrows {
+            for j in (i + 1)..ncols {
+                let x = self.get(i, j);
+                self.set(i, j, self.get(j, i));
+                self.set(j, i, x);
+            }
+        }
+    }
+
+    /// Return row `i` as a new vector.
+    fn get_row<V: BaseVector<T>>(&self, i: usize) -> V {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            i < nrows,
+            "get_row: row index {} out of bounds for {}x{} matrix",
+            i,
+            nrows,
+            ncols
+        );
+        let mut v = V::zeros(ncols);
+        for j in 0..ncols {
+            v.set(j, self.get(i, j));
+        }
+        v
+    }
+
+    /// Return column `j` as a new vector.
+    fn get_col<V: BaseVector<T>>(&self, j: usize) -> V {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            j < ncols,
+            "get_col: column index {} out of bounds for {}x{} matrix",
+            j,
+            nrows,
+            ncols
+        );
+        let mut v = V::zeros(nrows);
+        for i in 0..nrows {
+            v.set(i, self.get(i, j));
+        }
+        v
+    }
+
+    /// Overwrite row `i` with the elements of `row`.
+    fn set_row<V: BaseVector<T>>(&mut self, i: usize, row: &V) {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            i < nrows,
+            "set_row: row index {} out of bounds for {}x{} matrix",
+            i,
+            nrows,
+            ncols
+        );
+        assert!(
+            row.len() == ncols,
+            "set_row: row has {} elements, expected {}",
+            row.len(),
+            ncols
+        );
+        for j in 0..ncols {
+            self.set(i, j, row.get(j));
+        }
+    }
+
+    /// Overwrite column `j` with the elements of `col`.
+    fn set_col<V: BaseVector<T>>(&mut self, j: usize, col: &V) {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            j < ncols,
+            "set_col: column index {} out of bounds for {}x{} matrix",
+            j,
+            nrows,
+            ncols
+        );
+        assert!(
+            col.len() == nrows,
+            "set_col: column has {} elements, expected {}",
+            col.len(),
+            nrows
+        );
+        for i in 0..nrows {
+            self.set(i, j, col.get(i));
+        }
+    }
+
+    /// Swap rows `i` and `j`. Panics if either index is out of bounds.
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        let (nrows, ncols) = self.shape();
+        for &r in &[i, j] {
+            assert!(
+                r < nrows,
+                "swap_rows: row index {} out of bounds for {}x{} matrix",
+                r,
+                nrows,
+                ncols
+            );
+        }
+        if i == j {
+            return;
+        }
+        for c in 0..ncols {
+            let tmp = self.get(i, c);
+            self.set(i, c, self.get(j, c));
+            self.set(j, c, tmp);
+        }
+    }
+
+    /// Swap columns `i` and `j`. Panics if either index is out of bounds.
+    fn swap_cols(&mut self, i: usize, j: usize) {
+        let (nrows, ncols) = self.shape();
+        for &c in &[i, j] {
+            assert!(
+                c < ncols,
+                "swap_cols: column index {} out of bounds for {}x{} matrix",
+                c,
+                nrows,
+                ncols
+            );
+        }
+        if i == j {
+            return;
+        }
+        for r in 0..nrows {
+            let tmp = self.get(r, i);
+            self.set(r, i, self.get(r, j));
+            self.set(r, j, tmp);
+        }
+    }
+
+    /// Create the `n x n` identity matrix.
+    fn eye(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.set(i, i, T::one());
+        }
+        m
+    }
+
+    /// Identity matrix whose size is the smaller of the receiver's dimensions.
+    fn identity_like(&self) -> Self {
+        let (nrows, ncols) = self.shape();
+        Self::eye(nrows.min(ncols))
+    }
+
+    /// Create a square matrix with `v` on the main diagonal and zeros elsewhere.
+    fn from_diag<V: BaseVector<T>>(v: &V) -> Self {
+        let n = v.len();
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.set(i, i, v.get(i));
+        }
+        m
+    }
+
+    /// Main diagonal of the matrix, of length `min(nrows, ncols)`.
+    fn diag<V: BaseVector<T>>(&self) -> V {
+        let (nrows, ncols) = self.shape();
+        let n = nrows.min(ncols);
+        let mut v = V::zeros(n);
+        for i in 0..n {
+            v.set(i, self.get(i, i));
+        }
+        v
+    }
+
+    /// Overwrite the main diagonal with `v`. Panics if `v` does not have `min(nrows, ncols)` elements.
+    fn set_diag<V: BaseVector<T>>(&mut self, v: &V) {
+        let (nrows, ncols) = self.shape();
+        let n = nrows.min(ncols);
+        assert!(
+            v.len() == n,
+            "set_diag: diagonal has {} elements, expected {}",
+            v.len(),
+            n
+        );
+        for i in 0..n {
+            self.set(i, i, v.get(i));
+        }
+    }
+
+    /// Add `x` to every element of the main diagonal in place.
+    fn add_to_diag(&mut self, x: T) {
+        let (nrows, ncols) = self.shape();
+        for i in 0..nrows.min(ncols) {
+            self.set(i, i, self.get(i, i) + x);
+        }
+    }
+
+    /// Sum of the main diagonal. Panics if the matrix is not square.
+    fn trace(&self) -> T {
+        let (nrows, ncols) = self.shape();
+        assert!(
+            nrows == ncols,
+            "trace: matrix must be square, got {}x{}",
+            nrows,
+            ncols
+        );
+        (0..nrows).map(|i| self.get(i, i)).sum()
+    }
+
+    /// [Frobenius norm](https://en.wikipedia.org/wiki/Matrix_norm#Frobenius_norm), the square root of the sum of
+    /// squared entries.
+    fn frobenius_norm(&self) -> T {
+        let (nrows, ncols) = self.shape();
+        let mut sum = T::zero();
+        for i in 0..nrows {
+            for j in 0..ncols {
+                sum += self.get(i, j).square();
+            }
+        }
+        sum.sqrt()
+    }
+
+    /// Return false if any element is NaN or infinite.
+    fn all_finite(&self) -> bool {
+        let (nrows, ncols) = self.shape();
+        (0..nrows).all(|i| (0..ncols).all(|j| self.get(i, j).is_finite()))
+    }
+
+    /// Sum along `axis`: `0` reduces over rows and returns one value per column, `1` reduces over columns and returns
+    /// one value per row. Panics if `axis` is not 0 or 1.
+    fn sum_axis<V: BaseVector<T>>(&self, axis: usize) -> V {
+        reduce_axis(self, axis, |v: &V| v.sum())
+    }
+
+    /// Mean along `axis`, see [`sum_axis`](BaseMatrix::sum_axis).
+    fn mean_axis<V: BaseVector<T>>(&self, axis: usize) -> V {
+        reduce_axis(self, axis, |v: &V| v.mean())
+    }
+
+    /// Population standard deviation along `axis`, see [`sum_axis`](BaseMatrix::sum_axis).
+    fn std_axis<V: BaseVector<T>>(&self, axis: usize) -> V {
+        reduce_axis(self, axis, |v: &V| v.std())
+    }
+
+    /// Copy of the matrix with every element multiplied by `factor`.
+    fn scale(&self, factor: T) -> Self {
+        let mut r = self.clone();
+        r.scale_mut(factor);
+        r
+    }
+
+    /// Multiply every element by `factor` in place.
+    fn scale_mut(&mut self, factor: T) {
+        let (nrows, ncols) = self.shape();
+        for i in 0..nrows {
+            for j in 0..ncols {
+                self.set(i, j, self.get(i, j) * factor);
+            }
+        }
+    }
+
+    /// Concatenate the rows of the matrix into a single vector of length `nrows * ncols`.
+    fn flatten<V: BaseVector<T>>(&self) -> V {
+        let (nrows, ncols) = self.shape();
+        let mut v = V::zeros(nrows * ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                v.set(i * ncols + j, self.get(i, j));
+            }
+        }
+        v
+    }
+
+    /// Build a `nrows x ncols` matrix from a vector holding the rows one after another, the inverse of
+    /// [`flatten`](BaseMatrix::flatten). Panics if `nrows * ncols != v.len()`.
+    fn reshape<V: BaseVector<T>>(v: &V, nrows: usize, ncols: usize) -> Self {
+        assert!(
+            nrows * ncols == v.len(),
+            "reshape: cannot reshape vector of length {} into {}x{}",
+            v.len(),
+            nrows,
+            ncols
+        );
+        let mut m = Self::zeros(nrows, ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                m.set(i, j, v.get(i * ncols + j));
+            }
+        }
+        m
+    }
+
+    /// Compute the [LU decomposition](https://en.wikipedia.org/wiki/LU_decomposition) with partial pivoting.
+    fn lu(&self) -> Result<LU<T, Self>, Failure> {
+        LU::new(self)
+    }
+
+    /// Compute the [QR decomposition](https://en.wikipedia.org/wiki/QR_decomposition) using Householder reflections.
+    fn qr(&self) -> Result<QR<T, Self>, Failure> {
+        QR::new(self)
+    }
+
+    /// Compute the [Cholesky decomposition](https://en.wikipedia.org/wiki/Cholesky_decomposition) of a symmetric positive-definite matrix.
+    fn cholesky(&self) -> Result<Cholesky<T, Self>, Failure> {
+        Cholesky::new(self)
+    }
+
+    /// Compute the thin [singular value decomposition](https://en.wikipedia.org/wiki/Singular_value_decomposition).
+    fn svd(&self) -> Result<SVD<T, Self>, Failure> {
+        SVD::new(self)
+    }
+
+    /// Numerical rank, the number of singular values greater than `tol`. `None` uses
+    /// [`SVD::default_tol`], `max(nrows, ncols) * eps * σ_max`. Fails if the SVD fails.
+    fn matrix_rank(&self, tol: Option<T>) -> Result<usize, Failure> {
+        let svd = self.svd()?;
+        Ok(svd.rank(tol.unwrap_or_else(|| svd.default_tol())))
+    }
+
+    /// Compute the [Moore-Penrose pseudo-inverse](https://en.wikipedia.org/wiki/Moore%E2%80%93Penrose_inverse) using the SVD.
+    /// Singular values not greater than `tol` are treated as zero, so rank-deficient matrices are supported.
+    fn pinv(&self, tol: T) -> Result<Self, Failure> {
+        Ok(self.svd()?.pinv(tol))
+    }
+
+    /// Compute eigenvalues and eigenvectors of a symmetric matrix with the Jacobi eigenvalue algorithm.
+    /// Eigenvalues are sorted in descending order, eigenvectors are the matching columns of the returned matrix.
+    fn eigh<V: BaseVector<T>>(&self) -> Result<(V, Self), Failure> {
+        let (values, vectors) = symmetric_eigen(self)?;
+        Ok((V::from_array(&values), vectors))
+    }
+
+    /// Solve the triangular system `A·x = b` by back substitution when `upper` is true and forward substitution
+    /// otherwise. Only the selected triangle is read. With `unit_diag` the diagonal is taken to be all ones.
+    fn solve_triangular<V: BaseVector<T>>(
+        &self,
+        b: &V,
+        upper: bool,
+        unit_diag: bool,
+    ) -> Result<V, Failure> {
+        let (nrows, ncols) = self.shape();
+        if nrows != ncols {
+            return Err(Failure::because(
+                FailedError::SolutionFailed,
+                &format!(
+                    "solve_triangular requires a square matrix, got {}x{}",
+                    nrows, ncols
+                ),
+            ));
+        }
+        if b.len() != nrows {
+            return Err(Failure::because(
+                FailedError::SolutionFailed,
+                &format!("b has {} elements, expected {}", b.len(), nrows),
+            ));
+        }
+
+        let n = nrows;
+        let mut x = b.clone();
+        for step in 0..n {
+            let i = if upper { n - 1 - step } else { step };
+            let mut s = x.get(i);
+            let others = if upper { (i + 1)..n } else { 0..i };
+            for k in others {
+                s -= self.get(i, k) * x.get(k);
+            }
+            if !unit_diag {
+                let d = self.get(i, i);
+                if d == T::zero() {
+                    return Err(Failure::because(
+                        FailedError::SolutionFailed,
+                        &format!("zero on the diagonal at row {}", i),
+                    ));
+                }
+                s /= d;
+            }
+            x.set(i, s);
+        }
+        Ok(x)
+    }
+
+    /// Compute the inverse of a square matrix using the LU decomposition.
+    fn inv(&self) -> Result<Self, Failure> {
+        let (nrows, ncols) = self.shape();
+        if nrows != ncols {
+            return Err(Failure::because(
+                FailedError::SolutionFailed,
+                &format!("inv requires a square matrix, got {}x{}", nrows, ncols),
+            ));
+        }
+        self.lu()?.inverse()
+    }
+
+    /// Compute the sample [covariance matrix](https://en.wikipedia.org/wiki/Covariance_matrix) of shape
+    /// `(cols, cols)`, treating rows as samples and columns as features. Uses the `n - 1` denominator.
+    fn cov(&self) -> Result<Self, Failure> {
+        let (nrows, ncols) = self.shape();
+        if nrows <= 1 {
+            return Err(Failure::invalid_input(&format!(
+                "cov requires at least 2 samples, got {}",
+                nrows
+            )));
+        }
+        let n = T::from_usize(nrows).unwrap();
+        let mut centered = self.clone();
+        for j in 0..ncols {
+            let mut mean = T::zero();
+            for i in 0..nrows {
+                mean += self.get(i, j);
+            }
+            mean /= n;
+            for i in 0..nrows {
+                centered.set(i, j, self.get(i, j) - mean);
+            }
+        }
+        let mut cov = centered.transpose().matmul(&centered);
+        let denom = n - T::one();
+        for i in 0..ncols {
+            for j in 0..ncols {
+                cov.set(i, j, cov.get(i, j) / denom);
+            }
+        }
+        Ok(cov)
+    }
+}
+
+/// Apply `f` to every column (`axis == 0`) or every row (`axis == 1`) of `m`.
+fn reduce_axis<T: Real, M: BaseMatrix<T>, V: BaseVector<T>, F: Fn(&V) -> T>(
+    m: &M,
+    axis: usize,
+    f: F,
+) -> V {
+    let (nrows, ncols) = m.shape();
+    match axis {
+        0 => V::from_array(&(0..ncols).map(|j| f(&m.get_col(j))).collect::<Vec<T>>()),
+        1 => V::from_array(&(0..nrows).map(|i| f(&m.get_row(i))).collect::<Vec<T>>()),
+        _ => panic!("axis must be 0 or 1, got {}", axis),
+    }
+}
+
+#[cfg(any(not(feature = "parallel"), test))]
+fn matmul_serial<T: Real, M: BaseMatrix<T>>(a: &M, b: &M) -> M {
+    let (nrows, ncols) = a.shape();
+    let (_, b_ncols) = b.shape();
+    let mut r = M::zeros(nrows, b_ncols);
+    for i in 0..nrows {
+        for j in 0..b_ncols {
+            let mut s = T::zero();
+            for k in 0..ncols {
+                s += a.get(i, k) * b.get(k, j);
+            }
+            r.set(i, j, s);
+        }
+    }
+    r
+}
+
+/// Copies both operands into row-major buffers and computes the output rows in parallel.
+/// Each element is accumulated in the same order as [`matmul_serial`].
+#[cfg(feature = "parallel")]
+fn matmul_parallel<T: Real, M: BaseMatrix<T>>(a: &M, b: &M) -> M {
+    use rayon::prelude::*;
+
+    let (nrows, ncols) = a.shape();
+    let (_, b_ncols) = b.shape();
+    let a_buf: Vec<T> = (0..nrows)
+        .flat_map(|i| (0..ncols).map(move |k| (i, k)))
+        .map(|(i, k)| a.get(i, k))
+        .collect();
+    let b_buf: Vec<T> = (0..ncols)
+        .flat_map(|k| (0..b_ncols).map(move |j| (k, j)))
+        .map(|(k, j)| b.get(k, j))
+        .collect();
+
+    let mut out = vec![T::zero(); nrows * b_ncols];
+    if b_ncols > 0 {
+        out.par_chunks_mut(b_ncols)
+            .enumerate()
+            .for_each(|(i, row)| {
+                for (j, elem) in row.iter_mut().enumerate() {
+                    let mut s = T::zero();
+                    for k in 0..ncols {
+                        s += a_buf[i * ncols + k] * b_buf[k * b_ncols + j];
+                    }
+                    *elem = s;
+                }
+            });
+    }
+
+    let mut r = M::zeros(nrows, b_ncols);
+    for i in 0..nrows {
+        for j in 0..b_ncols {
+            r.set(i, j, out[i * b_ncols + j]);
+        }
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl<T: Real> BaseMatrix<T> for Vec<Vec<T>> {
+        fn get(&self, row: usize, col: usize) -> T {
+            self[row][col]
+        }
+
+        fn set(&mut self, row: usize, col: usize, x: T) {
+            self[row][col] = x;
+        }
+
+        fn shape(&self) -> (usize, usize) {
+            (self.len(), self.first().map_or(0, |r| r.len()))
+        }
+
+        fn zeros(nrows: usize, ncols: usize) -> Self {
+            vec![vec![T::zero(); ncols]; nrows]
+        }
+
+        fn ones(nrows: usize, ncols: usize) -> Self {
+            vec![vec![T::one(); ncols]; nrows]
+        }
+    }
+
+    #[test]
+    fn matmul() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        let b: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[3., 4.], &[5., 6.]]);
+        let expected = vec![vec![22., 28.], vec![49., 64.]];
+        assert_eq!(a.matmul(&b), expected);
+        assert_eq!(a.shape(), (2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "matmul: shape mismatch (2x3 and 2x3)")]
+    fn matmul_shape_mismatch() {
+        let a: Vec<Vec<f64>> = BaseMatrix::ones(2, 3);
+        a.matmul(&a);
+    }
+
+    #[test]
+    fn transpose() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        let t = a.transpose();
+        assert_eq!(t.shape(), (3, 2));
+        assert_eq!(t, vec![vec![1., 4.], vec![2., 5.], vec![3., 6.]]);
+        assert_eq!(t.transpose(), a);
+
+        let mut s: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[3., 4.]]);
+        s.transpose_mut();
+        assert_eq!(s, vec![vec![1., 3.], vec![2., 4.]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "transpose_mut: matrix must be square, got 2x3")]
+    fn transpose_mut_non_square() {
+        let mut a: Vec<Vec<f64>> = BaseMatrix::zeros(2, 3);
+        a.transpose_mut();
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let mut a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        let row: Vec<f64> = a.get_row(1);
+        let col: Vec<f64> = a.get_col(2);
+        assert_eq!(row, vec![4., 5., 6.]);
+        assert_eq!(col, vec![3., 6.]);
+
+        a.set_row(0, &vec![7., 8., 9.]);
+        a.set_col(1, &vec![0., 0.]);
+        assert_eq!(a, vec![vec![7., 0., 9.], vec![4., 0., 6.]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "get_col: column index 3 out of bounds for 2x3 matrix")]
+    fn get_col_out_of_bounds() {
+        let a: Vec<Vec<f64>> = BaseMatrix::zeros(2, 3);
+        let _: Vec<f64> = a.get_col(3);
+    }
+
+    #[test]
+    fn inv() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[4., 7., 2.], &[3., 6., 1.], &[2., 5., 3.]]);
+        let identity: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
+        let a_inv = a.inv().unwrap();
+        let product = a.matmul(&a_inv);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((product.get(i, j) - identity.get(i, j)).abs() < 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn inv_failures() {
+        let non_square: Vec<Vec<f64>> = BaseMatrix::zeros(2, 3);
+        assert_eq!(
+            non_square.inv().unwrap_err().error(),
+            FailedError::SolutionFailed
+        );
+
+        let singular: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[2., 4.]]);
+        assert_eq!(
+            singular.inv().unwrap_err().error(),
+            FailedError::DecompositionFailed
+        );
+    }
+
+    #[test]
+    fn cov() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[
+            &[2.1, 8., -1.],
+            &[2.5, 12., 1.],
+            &[3.6, 14., 0.],
+            &[4.0, 10., 2.],
+        ]);
+        let expected = [
+            vec![0.8033333333333333, 1., 0.7666666666666666],
+            vec![1., 6.666666666666667, 0.6666666666666666],
+            vec![0.7666666666666666, 0.6666666666666666, 1.6666666666666667],
+        ];
+        let cov = x.cov().unwrap();
+        for i in 0..3 {
+            assert!(cov[i].approximate_eq(&expected[i], 1e-12));
+        }
+
+        let single: Vec<Vec<f64>> = BaseMatrix::ones(1, 3);
+        assert_eq!(single.cov().unwrap_err().error(), FailedError::InvalidInput);
+    }
+
+    #[test]
+    fn pinv() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[
+            &[1., 2., 3.],
+            &[2., 4., 6.],
+            &[1., 0., 1.],
+            &[0., 2., 2.],
+        ]);
+        let a_pinv = a.pinv(1e-10).unwrap();
+        assert_eq!(a_pinv.shape(), (3, 4));
+
+        let a_back = a.matmul(&a_pinv).matmul(&a);
+        for i in 0..4 {
+            assert!(a_back[i].approximate_eq(&a[i], 1e-10));
+        }
+        let a_pinv_back = a_pinv.matmul(&a).matmul(&a_pinv);
+        for i in 0..3 {
+            assert!(a_pinv_back[i].approximate_eq(&a_pinv[i], 1e-10));
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn matmul_parallel_matches_serial() {
+        let a: Vec<Vec<f64>> = (0..37)
+            .map(|i| {
+                (0..23)
+                    .map(|j| ((i * 7 + j * 3) % 11) as f64 - 5.)
+                    .collect()
+            })
+            .collect();
+        let b: Vec<Vec<f64>> = (0..23)
+            .map(|i| (0..19).map(|j| ((i * 5 + j) % 13) as f64 - 6.).collect())
+            .collect();
+        assert_eq!(matmul_parallel(&a, &b), matmul_serial(&a, &b));
+        assert_eq!(a.matmul(&b), matmul_serial(&a, &b));
+    }
+
+    #[test]
+    fn flatten_reshape() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        let flat: Vec<f64> = a.flatten();
+        assert_eq!(flat, vec![1., 2., 3., 4., 5., 6.]);
+        let back: Vec<Vec<f64>> = BaseMatrix::reshape(&flat, 2, 3);
+        assert_eq!(back, a);
+        let column: Vec<Vec<f64>> = BaseMatrix::reshape(&flat, 6, 1);
+        assert_eq!(column.shape(), (6, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "reshape: cannot reshape vector of length 6 into 4x2")]
+    fn reshape_wrong_size() {
+        let _: Vec<Vec<f64>> = BaseMatrix::reshape(&vec![0f64; 6], 4, 2);
+    }
+
+    #[test]
+    fn swap_rows_cols() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.], &[7., 8., 9.]]);
+
+        let mut rows = a.clone();
+        rows.swap_rows(0, 2);
+        assert_eq!(rows, vec![a[2].clone(), a[1].clone(), a[0].clone()]);
+
+        let mut cols = a.clone();
+        cols.swap_cols(1, 2);
+        assert_eq!(
+            cols,
+            vec![vec![1., 3., 2.], vec![4., 6., 5.], vec![7., 9., 8.]]
+        );
+
+        let mut same = a.clone();
+        same.swap_rows(1, 1);
+        same.swap_cols(2, 2);
+        assert_eq!(same, a);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap_rows: row index 3 out of bounds for 3x2 matrix")]
+    fn swap_rows_out_of_bounds() {
+        let mut a: Vec<Vec<f64>> = BaseMatrix::zeros(3, 2);
+        a.swap_rows(3, 3);
+    }
+
+    #[test]
+    fn diag() {
+        let v: Vec<f64> = vec![1., 2., 3.];
+        let d: Vec<Vec<f64>> = BaseMatrix::from_diag(&v);
+        assert_eq!(
+            d,
+            vec![vec![1., 0., 0.], vec![0., 2., 0.], vec![0., 0., 3.]]
+        );
+        let back: Vec<f64> = d.diag();
+        assert_eq!(back, v);
+
+        let wide: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        let wide_diag: Vec<f64> = wide.diag();
+        assert_eq!(wide_diag, vec![1., 5.]);
+    }
+
+    #[test]
+    fn set_add_to_diag() {
+        let mut m: Vec<Vec<f64>> = BaseMatrix::zeros(3, 3);
+        m.add_to_diag(2.5);
+        assert_eq!(m, Vec::<Vec<f64>>::eye(3).scale(2.5));
+
+        let mut wide: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        wide.set_diag(&vec![-1., -2.]);
+        assert_eq!(wide, vec![vec![-1., 2., 3.], vec![4., -2., 6.]]);
+        wide.add_to_diag(1.);
+        assert_eq!(wide, vec![vec![0., 2., 3.], vec![4., -1., 6.]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "set_diag: diagonal has 3 elements, expected 2")]
+    fn set_diag_length_mismatch() {
+        let mut m: Vec<Vec<f64>> = BaseMatrix::zeros(2, 3);
+        m.set_diag(&vec![1., 2., 3.]);
+    }
+
+    #[test]
+    fn trace_frobenius_norm() {
+        for n in 0..4 {
+            let identity: Vec<Vec<f64>> = BaseMatrix::from_diag(&vec![1.; n]);
+            assert_eq!(identity.trace(), n as f64);
+        }
+
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., -2.], &[3., 4.]]);
+        assert_eq!(a.trace(), 5.);
+        assert_eq!(a.frobenius_norm(), 30f64.sqrt());
+    }
+
+    #[test]
+    #[should_panic(expected = "trace: matrix must be square, got 2x3")]
+    fn trace_non_square() {
+        let a: Vec<Vec<f64>> = BaseMatrix::zeros(2, 3);
+        a.trace();
+    }
+
+    #[test]
+    fn all_finite() {
+        let mut a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[3., 4.]]);
+        assert!(a.all_finite());
+        a.set(1, 0, f64::NAN);
+        assert!(!a.all_finite());
+        a.set(1, 0, f64::INFINITY);
+        assert!(!a.all_finite());
+    }
+
+    #[test]
+    fn axis_reductions() {
+        let m: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[3., 6., 9.]]);
+        assert_eq!(m.sum_axis::<Vec<f64>>(0), vec![4., 8., 12.]);
+        assert_eq!(m.sum_axis::<Vec<f64>>(1), vec![6., 18.]);
+        assert_eq!(
+            m.mean_axis::<Vec<f64>>(0),
+            vec![(1. + 3.) / 2., (2. + 6.) / 2., (3. + 9.) / 2.]
+        );
+        assert_eq!(m.mean_axis::<Vec<f64>>(1), vec![2., 6.]);
+        assert_eq!(m.std_axis::<Vec<f64>>(0), vec![1., 2., 3.]);
+        let s: Vec<f64> = m.std_axis(1);
+        assert!((s[0] - (2f64 / 3.).sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "axis must be 0 or 1")]
+    fn axis_out_of_range() {
+        let m: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.]]);
+        let _: Vec<f64> = m.sum_axis(2);
+    }
+
+    #[test]
+    fn eye() {
+        let i3: Vec<Vec<f64>> = BaseMatrix::eye(3);
+        for r in 0..3 {
+            for c in 0..3 {
+                assert_eq!(i3.get(r, c), if r == c { 1. } else { 0. });
+            }
+        }
+
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        assert_eq!(a.matmul(&BaseMatrix::eye(3)), a);
+        assert_eq!(Vec::<Vec<f64>>::eye(2).matmul(&a), a);
+        assert_eq!(a.identity_like(), Vec::<Vec<f64>>::eye(2));
+        assert_eq!(Vec::<Vec<f64>>::eye(0).shape(), (0, 0));
+    }
+
+    #[test]
+    fn scale() {
+        let s = Vec::<Vec<f64>>::eye(3).scale(2.);
+        assert_eq!(s.diag::<Vec<f64>>(), vec![2., 2., 2.]);
+        assert_eq!(s.trace(), 6.);
+
+        let mut a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., -2.], &[0.5, 3.]]);
+        a.scale_mut(-2.);
+        assert_eq!(a, vec![vec![-2., 4.], vec![-1., -6.]]);
+    }
+
+    #[test]
+    fn matvec() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        let v: Vec<f64> = vec![1., 0., -1.];
+        assert_eq!(a.matvec(&v), vec![1. - 3., 4. - 6.]);
+        let col: Vec<f64> = a.matmul(&vec![vec![1.], vec![0.], vec![-1.]]).get_col(0);
+        assert_eq!(a.matvec(&v), col);
+    }
+
+    #[test]
+    #[should_panic(expected = "matvec: shape mismatch (2x3 matrix and vector of length 2)")]
+    fn matvec_shape_mismatch() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.]]);
+        a.matvec(&vec![1., 2.]);
+    }
+
+    #[test]
+    fn matrix_rank() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., 2., 3.], &[4., 5., 6.], &[5., 7., 9.]]);
+        assert_eq!(a.matrix_rank(None).unwrap(), 2);
+        assert_eq!(Vec::<Vec<f64>>::eye(3).matrix_rank(None).unwrap(), 3);
+        assert_eq!(Vec::<Vec<f64>>::zeros(2, 3).matrix_rank(None).unwrap(), 0);
+
+        let nearly: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 1.], &[1., 1. + 1e-6]]);
+        assert_eq!(nearly.matrix_rank(None).unwrap(), 2);
+        assert_eq!(nearly.matrix_rank(Some(1e-3)).unwrap(), 1);
+
+        let nan: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., f64::NAN], &[0., 1.]]);
+        assert!(nan.matrix_rank(None).is_err());
+    }
+
+    #[test]
+    fn solve_triangular() {
+        let u: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[2., 1., -1.], &[0., 3., 2.], &[0., 0., 4.]]);
+        // x = [1, -2, 3]
+        let b = vec![-3., 0., 12.];
+        let x: Vec<f64> = u.solve_triangular(&b, true, false).unwrap();
+        assert!(x.approximate_eq(&vec![1., -2., 3.], 1e-12));
+
+        let l = u.transpose();
+        let b = vec![2., -5., 7.];
+        let x: Vec<f64> = l.solve_triangular(&b, false, false).unwrap();
+        assert!(x.approximate_eq(&vec![1., -2., 3.], 1e-12));
+
+        // the diagonal is ignored with unit_diag
+        let b = vec![1., -1., 0.];
+        let x: Vec<f64> = l.solve_triangular(&b, false, true).unwrap();
+        assert!(x.approximate_eq(&vec![1., -2., 5.], 1e-12));
+    }
+
+    #[test]
+    fn solve_triangular_failures() {
+        let u: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[0., 0.]]);
+        let b = vec![1., 1.];
+        let err = u.solve_triangular(&b, true, false).unwrap_err();
+        assert_eq!(err.error(), FailedError::SolutionFailed);
+        assert!(u.solve_triangular(&b, true, true).is_ok());
+
+        let b = vec![1., 1., 1.];
+        let err = u.solve_triangular(&b, true, false).unwrap_err();
+        assert_eq!(err.error(), FailedError::SolutionFailed);
+    }
+}
@@ -1,6 +1,33 @@
+pub mod cholesky;
+pub mod dense_matrix;
+pub mod dense_vector;
+pub mod dot;
+pub mod eigen;
+pub mod lu;
+pub mod matrix;
+pub mod qr;
+pub mod svd;
+
+use crate::error::Failure;
 use crate::numbers::Real;
+use std::cmp::Ordering;
 use std::fmt::Debug;
 
+pub use self::matrix::BaseMatrix;
+
+/// Kind of vector norm computed by [`BaseVector::norm_enum`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VectorNorm<T: Real> {
+    /// Sum of absolute values.
+    L1,
+    /// Euclidean length.
+    L2,
+    /// Largest absolute value.
+    LInf,
+    /// Norm of order `p`.
+    Lp(T),
+}
+
 /// Column or row vector
 pub trait BaseVector<T: Real>: Clone + Debug {
     /// Get an element of a vector
@@ -42,6 +69,8 @@ pub trait BaseVector<T: Real>: Clone + Debug {
     fn fill(len: usize, value: T) -> Self;
 
     /// Vector dot product
+    ///
+    /// Implementations must panic with `"dot: length mismatch (a=.., b=..)"` when the lengths differ.
     fn dot(&self, other: &Self) -> T;
 
     /// Return true if matrices are element-wise equal within a tolerance `eps`/
@@ -51,7 +80,41 @@ pub trait BaseVector<T: Real>: Clone + Debug {
     fn norm2(&self) -> T;
 
     /// Return [vectors norm](https://en.wikipedia.org/wiki/Matrix_norm) of order `p`.
-    fn norm(&self, p: T) -> T;
+    /// `p = 1` is delegated to [`l1_norm`](#method.l1_norm).
+    fn norm(&self, p: T) -> T {
+        if p.is_infinite() && p.is_sign_positive() {
+            (0..self.len()).fold(T::neg_infinity(), |m, i| m.max(self.get(i).abs()))
+        } else if p.is_infinite() && p.is_sign_negative() {
+            (0..self.len()).fold(T::infinity(), |m, i| m.min(self.get(i).abs()))
+        } else if p == T::one() {
+            self.l1_norm()
+        } else {
+            let mut norm = T::zero();
+            for i in 0..self.len() {
+                norm += self.get(i).abs().powf(p);
+            }
+            norm.powf(T::one() / p)
+        }
+    }
+
+    /// Return the vector norm selected by `kind`. `LInf` is the largest absolute value and is zero for an empty vector.
+    fn norm_enum(&self, kind: VectorNorm<T>) -> T {
+        match kind {
+            VectorNorm::L1 => self.l1_norm(),
+            VectorNorm::L2 => self.norm2(),
+            VectorNorm::LInf => (0..self.len()).fold(T::zero(), |m, i| m.max(self.get(i).abs())),
+            VectorNorm::Lp(p) => self.norm(p),
+        }
+    }
+
+    /// Return [L1 norm](https://en.wikipedia.org/wiki/Taxicab_geometry) of the vector, the sum of absolute values.
+    fn l1_norm(&self) -> T {
+        let mut norm = T::zero();
+        for i in 0..self.len() {
+            norm += self.get(i).abs();
+        }
+        norm
+    }
 
     /// Divide single element of the vector by `x`, write result to original vector.
     fn div_element_mut(&mut self, pos: usize, x: T);
@@ -105,6 +168,67 @@ pub trait BaseVector<T: Real>: Clone + Debug {
         r
     }
 
+    /// Add `x` to every element of the vector, overriding original vector with result.
+    fn add_scalar_mut(&mut self, x: T) -> &Self {
+        for i in 0..self.len() {
+            self.add_element_mut(i, x);
+        }
+        self
+    }
+
+    /// Subtract `x` from every element of the vector, overriding original vector with result.
+    fn sub_scalar_mut(&mut self, x: T) -> &Self {
+        for i in 0..self.len() {
+            self.sub_element_mut(i, x);
+        }
+        self
+    }
+
+    /// Multiply every element of the vector by `x`, overriding original vector with result.
+    fn mul_scalar_mut(&mut self, x: T) -> &Self {
+        for i in 0..self.len() {
+            self.mul_element_mut(i, x);
+        }
+        self
+    }
+
+    /// Divide every element of the vector by `x`, overriding original vector with result.
+    /// Division by zero follows IEEE 754 and yields `inf` or `NaN`.
+    fn div_scalar_mut(&mut self, x: T) -> &Self {
+        for i in 0..self.len() {
+            self.div_element_mut(i, x);
+        }
+        self
+    }
+
+    /// Add `x` to every element of the vector
+    fn add_scalar(&self, x: T) -> Self {
+        let mut r = self.clone();
+        r.add_scalar_mut(x);
+        r
+    }
+
+    /// Subtract `x` from every element of the vector
+    fn sub_scalar(&self, x: T) -> Self {
+        let mut r = self.clone();
+        r.sub_scalar_mut(x);
+        r
+    }
+
+    /// Multiply every element of the vector by `x`
+    fn mul_scalar(&self, x: T) -> Self {
+        let mut r = self.clone();
+        r.mul_scalar_mut(x);
+        r
+    }
+
+    /// Divide every element of the vector by `x`
+    fn div_scalar(&self, x: T) -> Self {
+        let mut r = self.clone();
+        r.div_scalar_mut(x);
+        r
+    }
+
     /// Calculates sum of all elements of the vector.
     fn sum(&self) -> T;
 
@@ -135,4 +259,887 @@ pub trait BaseVector<T: Real>: Clone + Debug {
     fn std(&self) -> T {
         self.var().sqrt()
     }
+
+    /// Return the index of the largest element. Ties resolve to the lowest index.
+    fn argmax(&self) -> usize {
+        assert!(!self.is_empty(), "argmax on empty vector");
+        let mut max_i = 0;
+        let mut max = self.get(0);
+        for i in 1..self.len() {
+            let xi = self.get(i);
+            if xi > max {
+                max = xi;
+                max_i = i;
+            }
+        }
+        max_i
+    }
+
+    /// Return the index of the smallest element. Ties resolve to the lowest index.
+    fn argmin(&self) -> usize {
+        assert!(!self.is_empty(), "argmin on empty vector");
+        let mut min_i = 0;
+        let mut min = self.get(0);
+        for i in 1..self.len() {
+            let xi = self.get(i);
+            if xi < min {
+                min = xi;
+                min_i = i;
+            }
+        }
+        min_i
+    }
+
+    /// Return the largest element of the vector.
+    fn max(&self) -> T {
+        assert!(!self.is_empty(), "max on empty vector");
+        self.get(self.argmax())
+    }
+
+    /// Return the smallest element of the vector.
+    fn min(&self) -> T {
+        assert!(!self.is_empty(), "min on empty vector");
+        self.get(self.argmin())
+    }
+
+    /// Return the cumulative sum of the elements, where element `i` holds the sum of elements `0..=i`.
+    fn cumsum(&self) -> Self {
+        let mut r = Self::zeros(self.len());
+        let mut acc = T::zero();
+        for i in 0..self.len() {
+            acc += self.get(i);
+            r.set(i, acc);
+        }
+        r
+    }
+
+    /// Replace every element with the cumulative sum of the elements up to and including it.
+    fn cumsum_mut(&mut self) {
+        let mut acc = T::zero();
+        for i in 0..self.len() {
+            acc += self.get(i);
+            self.set(i, acc);
+        }
+    }
+
+    /// First discrete difference, element `i` is `self[i + 1] - self[i]`. The result has one element less, vectors
+    /// shorter than two elements give an empty vector.
+    fn diff(&self) -> Self {
+        let n = self.len().saturating_sub(1);
+        let mut r = Self::zeros(n);
+        for i in 0..n {
+            r.set(i, self.get(i + 1) - self.get(i));
+        }
+        r
+    }
+
+    /// Apply [`diff`](BaseVector::diff) `n` times, `n = 0` returns a copy.
+    fn diff_n(&self, n: usize) -> Self {
+        (0..n).fold(self.clone(), |v, _| v.diff())
+    }
+
+    /// Compute the weighted arithmetic mean, where `weights` are normalized by their sum.
+    /// Returns NaN if the weights sum to zero.
+    fn weighted_mean(&self, weights: &Self) -> T {
+        assert!(
+            self.len() == weights.len(),
+            "weighted_mean: length mismatch (x={}, weights={})",
+            self.len(),
+            weights.len()
+        );
+        let w_sum = weights.sum();
+        if w_sum == T::zero() {
+            return T::nan();
+        }
+        let mut sum = T::zero();
+        for i in 0..self.len() {
+            sum += self.get(i) * weights.get(i);
+        }
+        sum / w_sum
+    }
+
+    /// Compute the weighted variance, where `weights` are normalized by their sum.
+    /// Returns NaN if the weights sum to zero.
+    fn weighted_var(&self, weights: &Self) -> T {
+        assert!(
+            self.len() == weights.len(),
+            "weighted_var: length mismatch (x={}, weights={})",
+            self.len(),
+            weights.len()
+        );
+        let w_sum = weights.sum();
+        if w_sum == T::zero() {
+            return T::nan();
+        }
+        let mut mu = T::zero();
+        let mut sum = T::zero();
+        for i in 0..self.len() {
+            let xi = self.get(i);
+            let wi = weights.get(i);
+            mu += wi * xi;
+            sum += wi * xi * xi;
+        }
+        mu /= w_sum;
+        sum / w_sum - mu * mu
+    }
+
+    /// Return a unit vector pointing in the same direction, scaled by its L2 norm.
+    /// A zero vector is returned unchanged.
+    fn normalize(&self) -> Self {
+        let mut r = self.clone();
+        r.normalize_mut();
+        r
+    }
+
+    /// Scale the vector to unit L2 length in place. A zero vector is left unchanged.
+    fn normalize_mut(&mut self) {
+        let norm = self.norm2();
+        if norm != T::zero() {
+            self.div_scalar_mut(norm);
+        }
+    }
+
+    /// Compute the median. For even-length vectors the two central elements are averaged.
+    /// NaN values are sorted after all other elements.
+    fn median(&self) -> T {
+        assert!(!self.is_empty(), "median on empty vector");
+        let mut x = self.to_vec();
+        sort_nan_last(&mut x);
+        let n = x.len();
+        if n % 2 == 1 {
+            x[n / 2]
+        } else {
+            (x[n / 2 - 1] + x[n / 2]) / T::two()
+        }
+    }
+
+    /// Compute the `q`-th percentile, `q` in `[0, 100]`, interpolating linearly between neighboring ranks.
+    /// NaN values are sorted after all other elements.
+    fn percentile(&self, q: T) -> T {
+        assert!(
+            q >= T::zero() && q <= T::from_f64(100.).unwrap(),
+            "percentile: q must be in [0, 100], got {}",
+            q
+        );
+        assert!(!self.is_empty(), "percentile on empty vector");
+        let mut x = self.to_vec();
+        sort_nan_last(&mut x);
+        let rank = q / T::from_f64(100.).unwrap() * T::from_usize(x.len() - 1).unwrap();
+        let lo = rank.floor().to_usize().unwrap();
+        let hi = rank.ceil().to_usize().unwrap();
+        let frac = rank - T::from_usize(lo).unwrap();
+        x[lo] + (x[hi] - x[lo]) * frac
+    }
+
+    /// Compute [cosine similarity](https://en.wikipedia.org/wiki/Cosine_similarity) between two vectors.
+    /// Returns zero if either vector has zero norm.
+    fn cosine_similarity(&self, other: &Self) -> T {
+        let dot = self.dot(other);
+        let norms = self.norm2() * other.norm2();
+        if norms == T::zero() {
+            T::zero()
+        } else {
+            dot / norms
+        }
+    }
+
+    /// Compute the [outer product](https://en.wikipedia.org/wiki/Outer_product), a `self.len() x other.len()` matrix
+    /// where element `(i, j)` is `self[i] * other[j]`.
+    fn outer<M: BaseMatrix<T>>(&self, other: &Self) -> M {
+        let mut r = M::zeros(self.len(), other.len());
+        for i in 0..self.len() {
+            for j in 0..other.len() {
+                r.set(i, j, self.get(i) * other.get(j));
+            }
+        }
+        r
+    }
+
+    /// Copy elements `start..end` into a new vector.
+    /// Panics if `start > end` or `end > self.len()`.
+    fn subvector(&self, start: usize, end: usize) -> Self {
+        assert!(
+            start <= end && end <= self.len(),
+            "subvector: range {}..{} out of bounds for vector of length {}",
+            start,
+            end,
+            self.len()
+        );
+        let mut r = Self::zeros(end - start);
+        for i in start..end {
+            r.set(i - start, self.get(i));
+        }
+        r
+    }
+
+    /// Copy the first `n` elements into a new vector. Panics if `n > self.len()`.
+    fn head(&self, n: usize) -> Self {
+        assert!(
+            n <= self.len(),
+            "head: n = {} exceeds vector length {}",
+            n,
+            self.len()
+        );
+        self.subvector(0, n)
+    }
+
+    /// Copy the last `n` elements into a new vector. Panics if `n > self.len()`.
+    fn tail(&self, n: usize) -> Self {
+        assert!(
+            n <= self.len(),
+            "tail: n = {} exceeds vector length {}",
+            n,
+            self.len()
+        );
+        self.subvector(self.len() - n, self.len())
+    }
+
+    /// Create a new vector with the elements of `self` followed by the elements of `other`.
+    fn concat(&self, other: &Self) -> Self {
+        let n = self.len();
+        let mut r = Self::zeros(n + other.len());
+        for i in 0..n {
+            r.set(i, self.get(i));
+        }
+        for i in 0..other.len() {
+            r.set(n + i, other.get(i));
+        }
+        r
+    }
+
+    /// Create a new vector with `f` applied to every element.
+    fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        let mut r = self.clone();
+        r.apply_mut(f);
+        r
+    }
+
+    /// Replace every element `x` with `f(x)`.
+    fn apply_mut<F: Fn(T) -> T>(&mut self, f: F) {
+        for i in 0..self.len() {
+            self.set(i, f(self.get(i)));
+        }
+    }
+
+    /// Dot product accumulated with pairwise summation. The rounding error grows as `O(log n)` instead of the `O(n)`
+    /// of [`dot`](BaseVector::dot), at the cost of an intermediate buffer of the products.
+    /// Panics with `"dot: length mismatch (a=.., b=..)"` when the lengths differ.
+    fn dot_pairwise(&self, other: &Self) -> T {
+        assert!(
+            self.len() == other.len(),
+            "dot: length mismatch (a={}, b={})",
+            self.len(),
+            other.len()
+        );
+        let products: Vec<T> = (0..self.len())
+            .map(|i| self.get(i) * other.get(i))
+            .collect();
+        pairwise_sum(&products)
+    }
+
+    /// Sum of all elements using pairwise summation, more accurate than [`sum`](BaseVector::sum) for long vectors.
+    fn sum_pairwise(&self) -> T {
+        pairwise_sum(&self.to_vec())
+    }
+
+    /// Return false if any element is NaN or infinite.
+    fn all_finite(&self) -> bool {
+        (0..self.len()).all(|i| self.get(i).is_finite())
+    }
+
+    /// Indices that would sort the vector, ascending or descending. The sort is stable, so equal elements keep
+    /// their input order, and NaN values are placed last in either direction.
+    fn argsort(&self, ascending: bool) -> Vec<usize> {
+        let mut idx: Vec<usize> = (0..self.len()).collect();
+        idx.sort_by(|&i, &j| {
+            let (a, b) = (self.get(i), self.get(j));
+            if ascending || a.is_nan() || b.is_nan() {
+                cmp_nan_last(&a, &b)
+            } else {
+                cmp_nan_last(&b, &a)
+            }
+        });
+        idx
+    }
+
+    /// Copy of the vector with every element restricted to `[min, max]`, NaN is kept. Panics if `min > max`.
+    fn clip(&self, min: T, max: T) -> Self {
+        let mut r = self.clone();
+        r.clip_mut(min, max);
+        r
+    }
+
+    /// Restrict every element to `[min, max]` in place, NaN is kept. Panics if `min > max`.
+    fn clip_mut(&mut self, min: T, max: T) {
+        assert!(
+            min <= max,
+            "clip: min must not be greater than max (min={}, max={})",
+            min,
+            max
+        );
+        self.apply_mut(|x| Real::clamp(x, min, max));
+    }
+
+    /// Count the elements falling into `bins` equal-width bins spanning `[min, max]` of the finite elements. Returns
+    /// the counts and the `bins + 1` edges, each bin is half-open except the last, which includes `max`. NaN and
+    /// infinite elements are ignored. A constant vector puts every element in the middle bin of the range
+    /// `[x - 0.5, x + 0.5]`, whose edges collapse onto `x` once `0.5` is below its precision. An empty vector uses
+    /// `[0, 1]`. Panics if `bins` is zero.
+    fn histogram(&self, bins: usize) -> (Vec<usize>, Vec<T>) {
+        assert!(bins > 0, "histogram: bins must be at least 1");
+        let finite: Vec<T> = (0..self.len())
+            .map(|i| self.get(i))
+            .filter(|x| x.is_finite())
+            .collect();
+        let (mut lo, mut hi) = finite
+            .iter()
+            .fold((T::infinity(), T::neg_infinity()), |(lo, hi), &x| {
+                (lo.min(x), hi.max(x))
+            });
+        let constant = !finite.is_empty() && lo == hi;
+        if finite.is_empty() {
+            lo = T::zero();
+            hi = T::one();
+        } else if constant {
+            lo -= T::half();
+            hi += T::half();
+        }
+
+        // Work on halved values when the range itself overflows, e.g. `[-f64::MAX, f64::MAX]`.
+        let scale = if (hi - lo).is_finite() {
+            T::one()
+        } else {
+            T::half()
+        };
+        let n_bins = T::from_usize(bins).unwrap();
+        let (lo_s, width_s) = (lo * scale, hi * scale - lo * scale);
+        let mut edges: Vec<T> = (0..=bins)
+            .map(|b| (lo_s + width_s * T::from_usize(b).unwrap() / n_bins) / scale)
+            .collect();
+        edges[0] = lo;
+        edges[bins] = hi;
+        let mut counts = vec![0; bins];
+        if constant {
+            counts[bins / 2] = finite.len();
+            return (counts, edges);
+        }
+        for x in finite {
+            if let Some(b) = ((x * scale - lo_s) / width_s * n_bins).floor().to_usize() {
+                counts[b.min(bins - 1)] += 1;
+            }
+        }
+        (counts, edges)
+    }
+
+    /// Copy of the vector with the absolute value of every element.
+    fn abs(&self) -> Self {
+        self.map(|x| x.abs())
+    }
+
+    /// Replace every element with its absolute value.
+    fn abs_mut(&mut self) {
+        self.apply_mut(|x| x.abs())
+    }
+
+    /// Copy of the vector with the reciprocal `1 / x` of every element, zeros become signed infinities.
+    fn recip(&self) -> Self {
+        self.map(|x| x.recip())
+    }
+
+    /// Replace every element with its reciprocal `1 / x`, zeros become signed infinities.
+    fn recip_mut(&mut self) {
+        self.apply_mut(|x| x.recip())
+    }
+}
+
+/// Place the columns of `b` to the right of the columns of `a`. Fails with `FailedError::InvalidInput` if the row
+/// counts differ.
+pub fn hstack<T: Real, M: BaseMatrix<T>>(a: &M, b: &M) -> Result<M, Failure> {
+    let (a_nrows, a_ncols) = a.shape();
+    let (b_nrows, b_ncols) = b.shape();
+    if a_nrows != b_nrows {
+        return Err(Failure::invalid_input(&format!(
+            "hstack: row counts differ ({}x{} and {}x{})",
+            a_nrows, a_ncols, b_nrows, b_ncols
+        )));
+    }
+    let mut r = M::zeros(a_nrows, a_ncols + b_ncols);
+    for i in 0..a_nrows {
+        for j in 0..a_ncols {
+            r.set(i, j, a.get(i, j));
+        }
+        for j in 0..b_ncols {
+            r.set(i, a_ncols + j, b.get(i, j));
+        }
+    }
+    Ok(r)
+}
+
+/// Place the rows of `b` below the rows of `a`. Fails with `FailedError::InvalidInput` if the column counts differ.
+pub fn vstack<T: Real, M: BaseMatrix<T>>(a: &M, b: &M) -> Result<M, Failure> {
+    let (a_nrows, a_ncols) = a.shape();
+    let (b_nrows, b_ncols) = b.shape();
+    if a_ncols != b_ncols {
+        return Err(Failure::invalid_input(&format!(
+            "vstack: column counts differ ({}x{} and {}x{})",
+            a_nrows, a_ncols, b_nrows, b_ncols
+        )));
+    }
+    let mut r = M::zeros(a_nrows + b_nrows, a_ncols);
+    for j in 0..a_ncols {
+        for i in 0..a_nrows {
+            r.set(i, j, a.get(i, j));
+        }
+        for i in 0..b_nrows {
+            r.set(a_nrows + i, j, b.get(i, j));
+        }
+    }
+    Ok(r)
+}
+
+/// Sort in ascending order, placing NaN values after all other elements.
+fn sort_nan_last<T: Real>(x: &mut [T]) {
+    x.sort_by(cmp_nan_last);
+}
+
+/// Total order on reals that treats NaN as greater than every other value.
+pub(crate) fn cmp_nan_last<T: Real>(a: &T, b: &T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap(),
+    }
+}
+
+/// Sum by recursively splitting `x` in halves, falling back to a plain loop for short blocks.
+fn pairwise_sum<T: Real>(x: &[T]) -> T {
+    if x.len() <= 8 {
+        return x.iter().fold(T::zero(), |acc, &xi| acc + xi);
+    }
+    let (left, right) = x.split_at(x.len() / 2);
+    pairwise_sum(left) + pairwise_sum(right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl<T: Real> BaseVector<T> for Vec<T> {
+        fn get(&self, i: usize) -> T {
+            self[i]
+        }
+
+        fn set(&mut self, i: usize, x: T) {
+            self[i] = x
+        }
+
+        fn len(&self) -> usize {
+            self.len()
+        }
+
+        fn to_vec(&self) -> Vec<T> {
+            self.clone()
+        }
+
+        fn zeros(len: usize) -> Self {
+            vec![T::zero(); len]
+        }
+
+        fn ones(len: usize) -> Self {
+            vec![T::one(); len]
+        }
+
+        fn fill(len: usize, value: T) -> Self {
+            vec![value; len]
+        }
+
+        fn dot(&self, other: &Self) -> T {
+            assert!(
+                self.len() == other.len(),
+                "dot: length mismatch (a={}, b={})",
+                self.len(),
+                other.len()
+            );
+            let mut result = T::zero();
+            for i in 0..self.len() {
+                result += self[i] * other[i];
+            }
+            result
+        }
+
+        fn approximate_eq(&self, other: &Self, eps: T) -> bool {
+            self.len() == other.len()
+                && self
+                    .iter()
+                    .zip(other.iter())
+                    .all(|(&a, &b)| (a - b).abs() <= eps)
+        }
+
+        fn norm2(&self) -> T {
+            self.iter().map(|&x| x * x).sum::<T>().sqrt()
+        }
+
+        fn div_element_mut(&mut self, pos: usize, x: T) {
+            self[pos] /= x;
+        }
+
+        fn mul_element_mut(&mut self, pos: usize, x: T) {
+            self[pos] *= x;
+        }
+
+        fn add_element_mut(&mut self, pos: usize, x: T) {
+            self[pos] += x
+        }
+
+        fn sub_element_mut(&mut self, pos: usize, x: T) {
+            self[pos] -= x;
+        }
+
+        fn add_mut(&mut self, other: &Self) -> &Self {
+            for i in 0..self.len() {
+                self[i] += other[i];
+            }
+            self
+        }
+
+        fn sub_mut(&mut self, other: &Self) -> &Self {
+            for i in 0..self.len() {
+                self[i] -= other[i];
+            }
+            self
+        }
+
+        fn mul_mut(&mut self, other: &Self) -> &Self {
+            for i in 0..self.len() {
+                self[i] *= other[i];
+            }
+            self
+        }
+
+        fn div_mut(&mut self, other: &Self) -> &Self {
+            for i in 0..self.len() {
+                self[i] /= other[i];
+            }
+            self
+        }
+
+        fn sum(&self) -> T {
+            self.iter().copied().sum()
+        }
+
+        fn unique(&self) -> Vec<T> {
+            let mut result = self.clone();
+            result.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            result.dedup();
+            result
+        }
+    }
+
+    #[test]
+    fn argmax_argmin() {
+        let v = vec![1., 3., -2., 3., -2.];
+        assert_eq!(v.argmax(), 1);
+        assert_eq!(v.argmin(), 2);
+        assert_eq!(BaseVector::max(&v), 3.);
+        assert_eq!(BaseVector::min(&v), -2.);
+    }
+
+    #[test]
+    #[should_panic(expected = "argmax on empty vector")]
+    fn argmax_empty() {
+        let v: Vec<f64> = Vec::new();
+        v.argmax();
+    }
+
+    #[test]
+    fn cumsum() {
+        let mut v = vec![1., 2., 3., -4.];
+        assert_eq!(v.cumsum(), vec![1., 3., 6., 2.]);
+        v.cumsum_mut();
+        assert_eq!(v, vec![1., 3., 6., 2.]);
+        assert!(Vec::<f64>::new().cumsum().is_empty());
+    }
+
+    #[test]
+    fn weighted_mean_var() {
+        let v: Vec<f64> = vec![1., 2., 3., 4.];
+        assert_eq!(v.weighted_mean(&vec![1., 1., 1., 1.]), v.mean());
+        assert_eq!(v.weighted_var(&vec![2., 2., 2., 2.]), v.var());
+        assert_eq!(v.weighted_mean(&vec![0., 0., 1., 3.]), 3.75);
+        assert!((v.weighted_var(&vec![0., 0., 1., 3.]) - 0.1875).abs() < 1e-12);
+        assert!(v.weighted_mean(&vec![0., 0., 0., 0.]).is_nan());
+        assert!(v.weighted_var(&vec![1., -1., 0., 0.]).is_nan());
+    }
+
+    #[test]
+    fn l1_norm() {
+        let v: Vec<f64> = vec![-3., 2., -0.5, 1.25];
+        assert_eq!(v.l1_norm(), 6.75);
+        assert!((v.l1_norm() - v.norm(1.)).abs() < 1e-10);
+        assert!((v.norm(2.) - v.norm2()).abs() < 1e-10);
+        assert_eq!(v.norm(f64::INFINITY), 3.);
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let v: Vec<f64> = vec![1., -2., 4.];
+        assert_eq!(v.add_scalar(1.), vec![2., -1., 5.]);
+        assert_eq!(v.sub_scalar(1.), vec![0., -3., 3.]);
+        assert_eq!(v.mul_scalar(2.), vec![2., -4., 8.]);
+        assert_eq!(v.div_scalar(2.), vec![0.5, -1., 2.]);
+
+        let r = vec![1., -1., 0.].div_scalar(0.);
+        assert_eq!(r[0], f64::INFINITY);
+        assert_eq!(r[1], f64::NEG_INFINITY);
+        assert!(r[2].is_nan());
+    }
+
+    #[test]
+    fn norm_enum() {
+        let v: Vec<f64> = vec![2.5, -7.25, 0., 6.];
+        let max_abs = v.iter().map(|x| x.abs()).fold(0., f64::max);
+        assert_eq!(v.norm_enum(VectorNorm::LInf), max_abs);
+        assert_eq!(v.norm_enum(VectorNorm::LInf), 7.25);
+        assert_eq!(v.norm_enum(VectorNorm::L1), v.l1_norm());
+        assert_eq!(v.norm_enum(VectorNorm::L2), v.norm2());
+        assert_eq!(v.norm_enum(VectorNorm::Lp(3.)), v.norm(3.));
+        assert_eq!(Vec::<f64>::new().norm_enum(VectorNorm::LInf), 0.);
+    }
+
+    #[test]
+    fn normalize() {
+        let v: Vec<f64> = vec![3., -4., 12.];
+        assert!((v.normalize().norm2() - 1.).abs() < 1e-12);
+        assert!(v
+            .normalize()
+            .approximate_eq(&vec![3. / 13., -4. / 13., 12. / 13.], 1e-12));
+
+        let mut z: Vec<f64> = vec![0., 0.];
+        z.normalize_mut();
+        assert_eq!(z, vec![0., 0.]);
+    }
+
+    #[test]
+    fn median_percentile() {
+        let v: Vec<f64> = vec![5., 1., 4., 2., 3.];
+        assert_eq!(v.median(), 3.);
+        assert_eq!(vec![4., 1., 3., 2.].median(), 2.5);
+        assert_eq!(v.percentile(0.), 1.);
+        assert_eq!(v.percentile(100.), 5.);
+        assert_eq!(v.percentile(50.), 3.);
+        assert!((v.percentile(30.) - 2.2).abs() < 1e-12);
+        assert_eq!(vec![f64::NAN, 3., 1., 2.].percentile(50.), 2.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "percentile: q must be in [0, 100]")]
+    fn percentile_out_of_range() {
+        vec![1., 2.].percentile(101.);
+    }
+
+    #[test]
+    fn cosine_similarity() {
+        let a: Vec<f64> = vec![1., 0.];
+        assert!((a.cosine_similarity(&vec![1., 1.]) - 0.5f64.sqrt()).abs() < 1e-12);
+        assert!((a.cosine_similarity(&vec![-2., 0.]) + 1.).abs() < 1e-12);
+        assert_eq!(a.cosine_similarity(&vec![0., 0.]), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "dot: length mismatch (a=3, b=2)")]
+    fn dot_length_mismatch() {
+        vec![1., 2., 3.].dot(&vec![1., 2.]);
+    }
+
+    #[test]
+    fn outer() {
+        let a: Vec<f64> = vec![1., 2.];
+        let b: Vec<f64> = vec![3., 4., 5.];
+        let m: Vec<Vec<f64>> = a.outer(&b);
+        assert_eq!(m, vec![vec![3., 4., 5.], vec![6., 8., 10.]]);
+    }
+
+    #[test]
+    fn subvector() {
+        let v: Vec<f64> = (0..10).map(|i| i as f64).collect();
+        assert_eq!(v.subvector(3, 7), vec![3., 4., 5., 6.]);
+        assert!(v.subvector(4, 4).is_empty());
+        assert_eq!(v.head(2), vec![0., 1.]);
+        assert_eq!(v.tail(3), vec![7., 8., 9.]);
+        assert_eq!(v.tail(10), v);
+    }
+
+    #[test]
+    #[should_panic(expected = "subvector: range 5..11 out of bounds for vector of length 10")]
+    fn subvector_out_of_bounds() {
+        let v: Vec<f64> = vec![0.; 10];
+        v.subvector(5, 11);
+    }
+
+    #[test]
+    fn concat() {
+        let a: Vec<f64> = vec![1., 2.];
+        let empty: Vec<f64> = Vec::new();
+        assert_eq!(a.concat(&vec![3., 4.]), vec![1., 2., 3., 4.]);
+        assert_eq!(a.concat(&empty), a);
+        assert_eq!(empty.concat(&a), a);
+    }
+
+    #[test]
+    fn map() {
+        let mut v: Vec<f64> = vec![1., -2., 3.];
+        assert_eq!(v.map(|x| x * x), vec![1., 4., 9.]);
+        assert_eq!(v.map(|x| x.sigmoid())[0], 1f64.sigmoid());
+        v.apply_mut(|x| x * x);
+        assert_eq!(v, vec![1., 4., 9.]);
+    }
+
+    #[test]
+    fn pairwise_summation() {
+        let v: Vec<f32> = vec![0.1; 1_000_000];
+        assert!((v.sum() - 100_000.).abs() > 100.);
+        assert!((v.sum_pairwise() - 100_000.).abs() < 1.);
+        assert!((v.dot_pairwise(&vec![2.; 1_000_000]) - 200_000.).abs() < 2.);
+
+        let w: Vec<f64> = vec![1., 2., 3.];
+        assert_eq!(w.dot_pairwise(&vec![4., 5., 6.]), w.dot(&vec![4., 5., 6.]));
+    }
+
+    #[test]
+    fn stack() {
+        use crate::linalg::dense_matrix::DenseMatrix;
+
+        let a = DenseMatrix::from_2d_array(&[&[1., 2.], &[3., 4.], &[5., 6.]]);
+        let b = DenseMatrix::from_2d_array(&[&[7.], &[8.], &[9.]]);
+        assert_eq!(
+            hstack(&a, &b).unwrap(),
+            DenseMatrix::from_2d_array(&[&[1., 2., 7.], &[3., 4., 8.], &[5., 6., 9.]])
+        );
+
+        let c = DenseMatrix::from_2d_array(&[&[0., -1.]]);
+        assert_eq!(
+            vstack(&a, &c).unwrap(),
+            DenseMatrix::from_2d_array(&[&[1., 2.], &[3., 4.], &[5., 6.], &[0., -1.]])
+        );
+
+        assert_eq!(
+            hstack(&a, &c).unwrap_err(),
+            Failure::invalid_input("hstack: row counts differ (3x2 and 1x2)")
+        );
+        assert_eq!(
+            vstack(&a, &b).unwrap_err().error(),
+            crate::error::FailedError::InvalidInput
+        );
+    }
+
+    #[test]
+    fn all_finite() {
+        assert!(vec![1., -2., 0.].all_finite());
+        assert!(Vec::<f64>::new().all_finite());
+        assert!(!vec![1., f64::NAN].all_finite());
+        assert!(!vec![f64::NEG_INFINITY, 1.].all_finite());
+    }
+
+    #[test]
+    fn argsort() {
+        let v: Vec<f64> = vec![3., 1., 2.];
+        assert_eq!(v.argsort(true), vec![1, 2, 0]);
+        assert_eq!(v.argsort(false), vec![0, 2, 1]);
+
+        let ties: Vec<f64> = vec![2., 1., 2., f64::NAN, 1.];
+        assert_eq!(ties.argsort(true), vec![1, 4, 0, 2, 3]);
+        assert_eq!(ties.argsort(false), vec![0, 2, 1, 4, 3]);
+        assert!(Vec::<f64>::new().argsort(true).is_empty());
+    }
+
+    #[test]
+    fn clip() {
+        let v: Vec<f64> = vec![-2., 0.5, 3.];
+        assert_eq!(v.clip(0., 1.), vec![0., 0.5, 1.]);
+
+        let mut w: Vec<f64> = vec![f64::NAN, -1.];
+        w.clip_mut(-0.5, 0.5);
+        assert!(w[0].is_nan());
+        assert_eq!(w[1], -0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "clip: min must not be greater than max (min=1, max=0)")]
+    fn clip_out_of_order() {
+        Vec::<f64>::new().clip(1., 0.);
+    }
+
+    #[test]
+    fn histogram() {
+        let v: Vec<f64> = vec![
+            0.,
+            1.,
+            2.,
+            3.,
+            4.,
+            5.,
+            6.,
+            7.,
+            8.,
+            10.,
+            f64::NAN,
+            f64::INFINITY,
+        ];
+        let (counts, edges) = v.histogram(5);
+        assert_eq!(counts, vec![2, 2, 2, 2, 2]);
+        assert_eq!(counts.iter().sum::<usize>(), 10);
+        assert_eq!(edges, vec![0., 2., 4., 6., 8., 10.]);
+
+        let (counts, edges) = vec![3f64; 4].histogram(3);
+        assert_eq!(counts, vec![0, 4, 0]);
+        assert_eq!(edges.first(), Some(&2.5));
+        assert_eq!(edges.last(), Some(&3.5));
+
+        let (counts, edges) = vec![1e20f64; 4].histogram(3);
+        assert_eq!(counts, vec![0, 4, 0]);
+        assert_eq!(edges.len(), 4);
+
+        let (counts, edges) = vec![f64::NAN].histogram(2);
+        assert_eq!(counts, vec![0, 0]);
+        assert_eq!(edges, vec![0., 0.5, 1.]);
+
+        let (counts, edges) = vec![f64::MAX, -f64::MAX, 0.].histogram(2);
+        assert_eq!(counts, vec![1, 2]);
+        assert_eq!(edges, vec![-f64::MAX, 0., f64::MAX]);
+    }
+
+    #[test]
+    #[should_panic(expected = "histogram: bins must be at least 1")]
+    fn histogram_zero_bins() {
+        vec![1f64].histogram(0);
+    }
+
+    #[test]
+    fn diff() {
+        let v: Vec<f64> = vec![1., 3., 6., 10.];
+        assert_eq!(v.diff(), vec![2., 3., 4.]);
+        assert_eq!(v.diff_n(2), vec![1., 1.]);
+        assert_eq!(v.diff_n(0), v);
+        assert!(v.diff_n(5).is_empty());
+        assert!(vec![1f64].diff().is_empty());
+        assert!(Vec::<f64>::new().diff().is_empty());
+        assert_eq!(v.cumsum().diff(), v[1..].to_vec());
+    }
+
+    #[test]
+    fn abs_recip() {
+        let v: Vec<f64> = vec![-1., 2., -3.];
+        assert_eq!(BaseVector::abs(&v), vec![1., 2., 3.]);
+        assert_eq!(vec![2f64, 4.].recip(), vec![0.5, 0.25]);
+        assert_eq!(
+            vec![0f64, -0.].recip(),
+            vec![f64::INFINITY, f64::NEG_INFINITY]
+        );
+
+        let mut w = v.clone();
+        w.abs_mut();
+        w.recip_mut();
+        assert_eq!(w, vec![1., 0.5, 1. / 3.]);
+    }
 }
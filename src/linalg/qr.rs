@@ -0,0 +1,195 @@
+//! # QR Decomposition
+//! Factorizes an `m x n` matrix \\( A \\), \\( m \geq n \\), as \\( A = QR \\) using Householder reflections, where \\( Q \\) has
+//! orthonormal columns and \\( R \\) is upper triangular. Least squares solutions obtained from the QR decomposition are
+//! more numerically stable than the normal equations.
+
+use crate::error::{FailedError, Failure};
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// Result of the QR decomposition
+#[derive(Clone, Debug)]
+pub struct QR<T: Real, M: BaseMatrix<T>> {
+    qr: M,
+    rdiag: Vec<T>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> QR<T, M> {
+    /// Decompose `a`, failing if it has fewer rows than columns.
+    pub fn new(a: &M) -> Result<Self, Failure> {
+        let (m, n) = a.shape();
+        if m < n {
+            return Err(Failure::because(
+                FailedError::DecompositionFailed,
+                &format!(
+                    "QR requires at least as many rows as columns, got {}x{}",
+                    m, n
+                ),
+            ));
+        }
+
+        let mut qr = a.clone();
+        let mut rdiag = vec![T::zero(); n];
+
+        for (k, rd) in rdiag.iter_mut().enumerate() {
+            let mut nrm = T::zero();
+            for i in k..m {
+                nrm = nrm.hypot(qr.get(i, k));
+            }
+            if nrm != T::zero() {
+                if qr.get(k, k) < T::zero() {
+                    nrm = -nrm;
+                }
+                for i in k..m {
+                    qr.set(i, k, qr.get(i, k) / nrm);
+                }
+                qr.set(k, k, qr.get(k, k) + T::one());
+
+                for j in (k + 1)..n {
+                    let mut s = T::zero();
+                    for i in k..m {
+                        s += qr.get(i, k) * qr.get(i, j);
+                    }
+                    s = -s / qr.get(k, k);
+                    for i in k..m {
+                        qr.set(i, j, qr.get(i, j) + s * qr.get(i, k));
+                    }
+                }
+            }
+            *rd = -nrm;
+        }
+
+        Ok(QR { qr, rdiag })
+    }
+
+    /// Upper triangular factor `R` of shape `n x n`.
+    pub fn r(&self) -> M {
+        let (_, n) = self.qr.shape();
+        let mut r = M::zeros(n, n);
+        for i in 0..n {
+            r.set(i, i, self.rdiag[i]);
+            for j in (i + 1)..n {
+                r.set(i, j, self.qr.get(i, j));
+            }
+        }
+        r
+    }
+
+    /// Orthogonal factor `Q` of shape `m x n`.
+    pub fn q(&self) -> M {
+        let (m, n) = self.qr.shape();
+        let mut q = M::zeros(m, n);
+        for k in (0..n).rev() {
+            q.set(k, k, T::one());
+            for j in k..n {
+                if self.qr.get(k, k) != T::zero() {
+                    let mut s = T::zero();
+                    for i in k..m {
+                        s += self.qr.get(i, k) * q.get(i, j);
+                    }
+                    s = -s / self.qr.get(k, k);
+                    for i in k..m {
+                        q.set(i, j, q.get(i, j) + s * self.qr.get(i, k));
+                    }
+                }
+            }
+        }
+        q
+    }
+
+    /// Least squares solution of `A·x = b`, minimizing `||A·x - b||`.
+    pub fn solve<V: BaseVector<T>>(&self, b: &V) -> Result<V, Failure> {
+        let (m, n) = self.qr.shape();
+        if b.len() != m {
+            return Err(Failure::because(
+                FailedError::SolutionFailed,
+                &format!("b has {} elements, expected {}", b.len(), m),
+            ));
+        }
+
+        let max_rdiag = self.rdiag.iter().fold(T::zero(), |a, r| a.max(r.abs()));
+        let tol = T::epsilon() * max_rdiag * T::from_usize(m).unwrap();
+        if let Some(k) = self.rdiag.iter().position(|r| r.abs() <= tol) {
+            return Err(Failure::because(
+                FailedError::SolutionFailed,
+                &format!("matrix is rank deficient at column {}", k),
+            ));
+        }
+
+        let mut y = b.to_vec();
+        for k in 0..n {
+            let mut s = T::zero();
+            for (i, &yi) in y.iter().enumerate().skip(k) {
+                s += self.qr.get(i, k) * yi;
+            }
+            s = -s / self.qr.get(k, k);
+            for (i, yi) in y.iter_mut().enumerate().skip(k) {
+                *yi += s * self.qr.get(i, k);
+            }
+        }
+
+        let mut x = V::zeros(n);
+        for k in (0..n).rev() {
+            let mut s = y[k];
+            for j in (k + 1)..n {
+                s -= self.qr.get(k, j) * x.get(j);
+            }
+            x.set(k, s / self.rdiag[k]);
+        }
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: &[Vec<f64>], b: &[Vec<f64>], eps: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(a, b)| a.approximate_eq(b, eps))
+    }
+
+    #[test]
+    fn reconstruct() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[
+            &[12., -51., 4.],
+            &[6., 167., -68.],
+            &[-4., 24., -41.],
+            &[1., 2., 3.],
+        ]);
+        let qr = a.qr().unwrap();
+        let q = qr.q();
+        let r = qr.r();
+
+        assert!(approx_eq(&q.matmul(&r), &a, 1e-8));
+
+        let identity: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
+        assert!(approx_eq(&q.transpose().matmul(&q), &identity, 1e-8));
+
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(r.get(i, j), 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn least_squares() {
+        let a: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., 0.], &[1., 1.], &[1., 2.], &[1., 3.]]);
+        let b = vec![1., 3., 5., 7.];
+
+        let x = a.qr().unwrap().solve(&b).unwrap();
+        assert!(x.approximate_eq(&vec![1., 2.], 1e-10));
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[2., 4.], &[3., 6.]]);
+        let err = a.qr().unwrap().solve(&vec![1., 2., 3.]).unwrap_err();
+        assert_eq!(err.error(), FailedError::SolutionFailed);
+    }
+}
@@ -0,0 +1,245 @@
+//! # Singular Value Decomposition
+//! Factorizes an `m x n` matrix \\( A \\) as \\( A = U \Sigma V^T \\) using one-sided Jacobi rotations, where \\( U \\) and
+//! \\( V \\) have orthonormal columns and \\( \Sigma \\) is diagonal with non-negative singular values. The thin form is
+//! returned, with `k = min(m, n)` singular values.
+
+use crate::error::{FailedError, Failure};
+use crate::linalg::{cmp_nan_last, BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// Upper bound on Jacobi sweeps over all column pairs.
+const MAX_SWEEPS: usize = 100;
+
+/// Result of the singular value decomposition
+#[derive(Clone, Debug)]
+pub struct SVD<T: Real, M: BaseMatrix<T>> {
+    u: M,
+    s: Vec<T>,
+    v: M,
+}
+
+impl<T: Real, M: BaseMatrix<T>> SVD<T, M> {
+    /// Decompose `a`. Fails if `a` contains NaN or infinite values, or if some pair of columns is still not
+    /// orthogonal after `MAX_SWEEPS` sweeps.
+    pub fn new(a: &M) -> Result<Self, Failure> {
+        if !a.all_finite() {
+            return Err(Failure::because(
+                FailedError::DecompositionFailed,
+                "svd requires finite values, got NaN or infinity",
+            ));
+        }
+        let (m, n) = a.shape();
+        if m < n {
+            let svd = SVD::new(&a.transpose())?;
+            return Ok(SVD {
+                u: svd.v,
+                s: svd.s,
+                v: svd.u,
+            });
+        }
+
+        let mut u = a.clone();
+        let mut v = M::eye(n);
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let mut alpha = T::zero();
+                    let mut beta = T::zero();
+                    let mut gamma = T::zero();
+                    for i in 0..m {
+                        alpha += u.get(i, p).square();
+                        beta += u.get(i, q).square();
+                        gamma += u.get(i, p) * u.get(i, q);
+                    }
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (T::two() * gamma);
+                    let t = zeta.sign_nonzero() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+                    for i in 0..m {
+                        let uip = u.get(i, p);
+                        let uiq = u.get(i, q);
+                        u.set(i, p, c * uip - s * uiq);
+                        u.set(i, q, s * uip + c * uiq);
+                    }
+                    for i in 0..n {
+                        let vip = v.get(i, p);
+                        let viq = v.get(i, q);
+                        v.set(i, p, c * vip - s * viq);
+                        v.set(i, q, s * vip + c * viq);
+                    }
+                }
+            }
+            if !rotated {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(Failure::because(
+                FailedError::DecompositionFailed,
+                &format!(
+                    "one-sided Jacobi SVD did not converge in {} sweeps",
+                    MAX_SWEEPS
+                ),
+            ));
+        }
+
+        let norms: Vec<T> = (0..n)
+            .map(|j| (0..m).map(|i| u.get(i, j).square()).sum::<T>().sqrt())
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| cmp_nan_last(&norms[j], &norms[i]));
+
+        let mut u_sorted = M::zeros(m, n);
+        let mut v_sorted = M::zeros(n, n);
+        let mut s = Vec::with_capacity(n);
+        for (c, &j) in order.iter().enumerate() {
+            let sj = norms[j];
+            for i in 0..m {
+                if sj > T::zero() {
+                    u_sorted.set(i, c, u.get(i, j) / sj);
+                }
+            }
+            for i in 0..n {
+                v_sorted.set(i, c, v.get(i, j));
+            }
+            s.push(sj);
+        }
+
+        Ok(SVD {
+            u: u_sorted,
+            s,
+            v: v_sorted,
+        })
+    }
+
+    /// Left singular vectors as columns of an `m x k` matrix. Columns paired with a zero singular value are zero.
+    pub fn u(&self) -> M {
+        self.u.clone()
+    }
+
+    /// Singular values in descending order.
+    pub fn s<V: BaseVector<T>>(&self) -> V {
+        V::from_array(&self.s)
+    }
+
+    /// Right singular vectors as columns of an `n x k` matrix.
+    pub fn v(&self) -> M {
+        self.v.clone()
+    }
+
+    /// Pseudo-inverse `V·Σ⁺·Uᵀ` of the decomposed matrix, where singular values not greater than `tol` are treated as zero.
+    pub fn pinv(&self, tol: T) -> M {
+        let (m, k) = self.u.shape();
+        let (n, _) = self.v.shape();
+        let mut pinv = M::zeros(n, m);
+        for c in 0..k {
+            if self.s[c] <= tol {
+                continue;
+            }
+            for i in 0..n {
+                let vi = self.v.get(i, c) / self.s[c];
+                for j in 0..m {
+                    pinv.set(i, j, pinv.get(i, j) + vi * self.u.get(j, c));
+                }
+            }
+        }
+        pinv
+    }
+
+    /// Number of singular values greater than `tol`.
+    pub fn rank(&self, tol: T) -> usize {
+        self.s.iter().filter(|&&s| s > tol).count()
+    }
+
+    /// Conventional rank tolerance `max(m, n) * eps * σ_max` for an `m x n` matrix, where `eps` is the machine
+    /// epsilon of `T`.
+    pub fn default_tol(&self) -> T {
+        let (m, _) = self.u.shape();
+        let (n, _) = self.v.shape();
+        let s_max = self.s.first().copied().unwrap_or_else(T::zero);
+        T::from_usize(m.max(n)).unwrap() * T::epsilon() * s_max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: &[Vec<f64>], b: &[Vec<f64>], eps: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(a, b)| a.approximate_eq(b, eps))
+    }
+
+    fn reconstruct(svd: &SVD<f64, Vec<Vec<f64>>>) -> Vec<Vec<f64>> {
+        let s: Vec<f64> = svd.s();
+        let mut us = svd.u();
+        for row in us.iter_mut() {
+            for (u, &sj) in row.iter_mut().zip(s.iter()) {
+                *u *= sj;
+            }
+        }
+        us.matmul(&svd.v().transpose())
+    }
+
+    #[test]
+    fn reconstruct_and_orthogonality() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[
+            &[3., 2., 2.],
+            &[2., 3., -2.],
+            &[1., 0., 4.],
+            &[-1., 5., 2.],
+        ]);
+        let svd = a.svd().unwrap();
+        assert!(approx_eq(&reconstruct(&svd), &a, 1e-10));
+
+        let identity: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]);
+        let (u, v) = (svd.u(), svd.v());
+        assert!(approx_eq(&u.transpose().matmul(&u), &identity, 1e-10));
+        assert!(approx_eq(&v.transpose().matmul(&v), &identity, 1e-10));
+
+        let s: Vec<f64> = svd.s();
+        assert!(s[0] >= s[1] && s[1] >= s[2]);
+    }
+
+    #[test]
+    fn wide_matrix() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[3., 2., 2.], &[2., 3., -2.]]);
+        let svd = a.svd().unwrap();
+        let s: Vec<f64> = svd.s();
+
+        assert!(s.approximate_eq(&vec![5., 3.], 1e-10));
+        assert_eq!(svd.u().shape(), (2, 2));
+        assert_eq!(svd.v().shape(), (3, 2));
+        assert!(approx_eq(&reconstruct(&svd), &a, 1e-10));
+    }
+
+    #[test]
+    fn rank() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[2., 4.], &[3., 6.]]);
+        assert_eq!(a.svd().unwrap().rank(1e-10), 1);
+    }
+
+    #[test]
+    fn non_finite() {
+        let a: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., f64::INFINITY], &[2., 4.]]);
+        assert_eq!(
+            a.svd().unwrap_err(),
+            Failure::because(
+                FailedError::DecompositionFailed,
+                "svd requires finite values, got NaN or infinity"
+            )
+        );
+    }
+}
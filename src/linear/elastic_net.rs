@@ -0,0 +1,225 @@
+//! # Elastic Net Regression
+//! Least squares with a mix of L1 and L2 penalties, minimizing
+//! \\( \frac{1}{2n}\lVert y - Xw - b \rVert^2 + \alpha \rho \lVert w \rVert_1 + \frac{\alpha (1 - \rho)}{2} \lVert w \rVert_2^2 \\)
+//! with cyclic coordinate descent, where \\( \rho \\) is `l1_ratio`. `l1_ratio = 1` is the [Lasso](../lasso/index.html)
+//! and `l1_ratio = 0` is [Ridge](../ridge/index.html) with strength \\( n\alpha \\). The intercept, when fitted, is not
+//! penalized.
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Regressor};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::linear::{coordinate_descent, linear_predict};
+use crate::numbers::Real;
+
+/// Elastic net parameters
+#[derive(Clone, Debug)]
+pub struct ElasticNetParameters<T: Real> {
+    /// Overall regularization strength, must be non-negative.
+    pub alpha: T,
+    /// Share of the L1 penalty in `[0, 1]`.
+    pub l1_ratio: T,
+    /// Fit an unpenalized intercept term.
+    pub fit_intercept: bool,
+    /// Maximum number of passes over all coordinates.
+    pub max_iter: usize,
+    /// Stop when no coefficient changes by more than this value during a pass.
+    pub tol: T,
+    /// Return an error instead of the last iterate when `max_iter` is reached without converging.
+    pub fail_on_nonconvergence: bool,
+}
+
+impl<T: Real> Default for ElasticNetParameters<T> {
+    fn default() -> Self {
+        ElasticNetParameters {
+            alpha: T::one(),
+            l1_ratio: T::half(),
+            fit_intercept: true,
+            max_iter: 1000,
+            tol: T::from_f64(1e-4).unwrap(),
+            fail_on_nonconvergence: false,
+        }
+    }
+}
+
+/// Linear regression with combined L1 and L2 regularization
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ElasticNet<T: Real, M: BaseMatrix<T>> {
+    coefficients: M,
+    intercept: T,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for ElasticNet<T, M> {
+    fn default() -> Self {
+        ElasticNet {
+            coefficients: M::zeros(0, 1),
+            intercept: T::zero(),
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> ElasticNet<T, M> {
+    /// Fitted coefficients as a column matrix, one row per feature.
+    pub fn coefficients(&self) -> &M {
+        &self.coefficients
+    }
+
+    /// Fitted intercept, zero when `fit_intercept` is off.
+    pub fn intercept(&self) -> T {
+        self.intercept
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, ElasticNetParameters<T>, Failure>
+    for ElasticNet<T, M>
+{
+    fn fit(self, x: &M, y: &M, fit_params: ElasticNetParameters<T>) -> Result<Self, Failure> {
+        let (nrows, _) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if fit_params.alpha < T::zero() {
+            return Err(Failure::fit(&format!(
+                "alpha must be non-negative, got {}",
+                fit_params.alpha
+            )));
+        }
+        if !(fit_params.l1_ratio >= T::zero() && fit_params.l1_ratio <= T::one()) {
+            return Err(Failure::invalid_input(&format!(
+                "l1_ratio must be in [0, 1], got {}",
+                fit_params.l1_ratio
+            )));
+        }
+
+        let (coefficients, intercept, converged) = coordinate_descent(
+            x,
+            y,
+            fit_params.alpha * fit_params.l1_ratio,
+            fit_params.alpha * (T::one() - fit_params.l1_ratio),
+            fit_params.fit_intercept,
+            fit_params.max_iter,
+            fit_params.tol,
+        );
+        if !converged && fit_params.fail_on_nonconvergence {
+            return Err(Failure::fit(&format!(
+                "coordinate descent did not converge in {} iterations",
+                fit_params.max_iter
+            )));
+        }
+
+        Ok(ElasticNet {
+            coefficients,
+            intercept,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Regressor<M, Failure> for ElasticNet<T, M> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        linear_predict(x, &self.coefficients, self.intercept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+    use crate::linear::lasso::{Lasso, LassoParameters};
+    use crate::linear::ridge::{RidgeRegression, RidgeRegressionParameters};
+
+    fn data() -> (DenseMatrix<f64>, DenseMatrix<f64>) {
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 2.],
+            &[2., 1.],
+            &[3., 5.],
+            &[4., 3.],
+            &[5., 8.],
+            &[6., 4.],
+        ]);
+        let y = DenseMatrix::from_2d_array(&[&[6.1], &[5.9], &[13.2], &[11.8], &[21.1], &[15.9]]);
+        (x, y)
+    }
+
+    fn elastic_net(l1_ratio: f64) -> ElasticNet<f64, DenseMatrix<f64>> {
+        let (x, y) = data();
+        ElasticNet::default()
+            .fit(
+                &x,
+                &y,
+                ElasticNetParameters {
+                    alpha: 0.3,
+                    l1_ratio,
+                    tol: 1e-12,
+                    max_iter: 100_000,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn l1_ratio_one_matches_lasso() {
+        let (x, y) = data();
+        let lasso = Lasso::default()
+            .fit(
+                &x,
+                &y,
+                LassoParameters {
+                    alpha: 0.3,
+                    tol: 1e-12,
+                    max_iter: 100_000,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let enet = elastic_net(1.);
+
+        assert_eq!(enet.coefficients(), lasso.coefficients());
+        assert_eq!(enet.intercept(), lasso.intercept());
+    }
+
+    #[test]
+    fn l1_ratio_zero_matches_ridge() {
+        let (x, y) = data();
+        let ridge = RidgeRegression::default()
+            .fit(
+                &x,
+                &y,
+                RidgeRegressionParameters {
+                    alpha: 6. * 0.3,
+                    fit_intercept: true,
+                },
+            )
+            .unwrap();
+        let enet = elastic_net(0.);
+
+        for j in 0..2 {
+            assert!((enet.coefficients().get(j, 0) - ridge.coefficients().get(j, 0)).abs() < 1e-8);
+        }
+        assert!((enet.intercept() - ridge.intercept()).abs() < 1e-8);
+    }
+
+    #[test]
+    fn invalid_l1_ratio() {
+        let (x, y) = data();
+        let err = ElasticNet::<f64, DenseMatrix<f64>>::default()
+            .fit(
+                &x,
+                &y,
+                ElasticNetParameters {
+                    l1_ratio: 1.5,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Failure::invalid_input("l1_ratio must be in [0, 1], got 1.5")
+        );
+    }
+}
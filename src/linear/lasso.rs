@@ -0,0 +1,211 @@
+//! # Lasso Regression
+//! Least squares with an L1 penalty, minimizing \\( \frac{1}{2n}\lVert y - Xw - b \rVert^2 + \alpha \lVert w \rVert_1 \\)
+//! with cyclic coordinate descent. Soft-thresholding sets coefficients exactly to zero, so the solution is sparse.
+//! The intercept, when fitted, is not penalized.
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Regressor};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::linear::{coordinate_descent, linear_predict};
+use crate::numbers::Real;
+
+/// Lasso regression parameters
+#[derive(Clone, Debug)]
+pub struct LassoParameters<T: Real> {
+    /// Regularization strength, must be non-negative.
+    pub alpha: T,
+    /// Fit an unpenalized intercept term.
+    pub fit_intercept: bool,
+    /// Maximum number of passes over all coordinates.
+    pub max_iter: usize,
+    /// Stop when no coefficient changes by more than this value during a pass.
+    pub tol: T,
+    /// Return an error instead of the last iterate when `max_iter` is reached without converging.
+    pub fail_on_nonconvergence: bool,
+}
+
+impl<T: Real> Default for LassoParameters<T> {
+    fn default() -> Self {
+        LassoParameters {
+            alpha: T::one(),
+            fit_intercept: true,
+            max_iter: 1000,
+            tol: T::from_f64(1e-4).unwrap(),
+            fail_on_nonconvergence: false,
+        }
+    }
+}
+
+/// Linear regression with L1 regularization
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Lasso<T: Real, M: BaseMatrix<T>> {
+    coefficients: M,
+    intercept: T,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for Lasso<T, M> {
+    fn default() -> Self {
+        Lasso {
+            coefficients: M::zeros(0, 1),
+            intercept: T::zero(),
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Lasso<T, M> {
+    /// Fitted coefficients as a column matrix, one row per feature.
+    pub fn coefficients(&self) -> &M {
+        &self.coefficients
+    }
+
+    /// Fitted intercept, zero when `fit_intercept` is off.
+    pub fn intercept(&self) -> T {
+        self.intercept
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, LassoParameters<T>, Failure> for Lasso<T, M> {
+    fn fit(self, x: &M, y: &M, fit_params: LassoParameters<T>) -> Result<Self, Failure> {
+        let (nrows, _) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if fit_params.alpha < T::zero() {
+            return Err(Failure::fit(&format!(
+                "alpha must be non-negative, got {}",
+                fit_params.alpha
+            )));
+        }
+
+        let (coefficients, intercept, converged) = coordinate_descent(
+            x,
+            y,
+            fit_params.alpha,
+            T::zero(),
+            fit_params.fit_intercept,
+            fit_params.max_iter,
+            fit_params.tol,
+        );
+        if !converged && fit_params.fail_on_nonconvergence {
+            return Err(Failure::fit(&format!(
+                "coordinate descent did not converge in {} iterations",
+                fit_params.max_iter
+            )));
+        }
+
+        Ok(Lasso {
+            coefficients,
+            intercept,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Regressor<M, Failure> for Lasso<T, M> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        linear_predict(x, &self.coefficients, self.intercept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+
+    fn data() -> (DenseMatrix<f64>, DenseMatrix<f64>) {
+        // y depends on the first feature only, the second one is noise
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 0.3],
+            &[2., -0.1],
+            &[3., 0.4],
+            &[4., -0.2],
+            &[5., 0.1],
+            &[6., -0.4],
+        ]);
+        let y = DenseMatrix::from_2d_array(&[&[5.], &[7.], &[9.], &[11.], &[13.], &[15.]]);
+        (x, y)
+    }
+
+    #[test]
+    fn small_alpha_recovers_fit() {
+        let (x, y) = data();
+        let lasso = Lasso::default()
+            .fit(
+                &x,
+                &y,
+                LassoParameters {
+                    alpha: 1e-6,
+                    tol: 1e-10,
+                    max_iter: 10_000,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!((lasso.coefficients().get(0, 0) - 2.).abs() < 1e-3);
+        assert!((lasso.intercept() - 3.).abs() < 1e-2);
+        assert!(lasso
+            .predict(&x)
+            .unwrap()
+            .as_slice()
+            .iter()
+            .zip(y.as_slice())
+            .all(|(a, b)| (a - b).abs() < 1e-2));
+    }
+
+    #[test]
+    fn large_alpha_is_sparse() {
+        let (x, y) = data();
+        let lasso = Lasso::default()
+            .fit(
+                &x,
+                &y,
+                LassoParameters {
+                    alpha: 0.5,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(lasso.coefficients().get(1, 0), 0.);
+        assert!(lasso.coefficients().get(0, 0) > 1.);
+
+        let lasso = Lasso::default()
+            .fit(
+                &x,
+                &y,
+                LassoParameters {
+                    alpha: 100.,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(lasso.coefficients().as_slice(), &[0., 0.]);
+        assert!((lasso.intercept() - 10.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fail_on_nonconvergence() {
+        let (x, y) = data();
+        let err = Lasso::default()
+            .fit(
+                &x,
+                &y,
+                LassoParameters {
+                    alpha: 1e-6,
+                    tol: 0.,
+                    max_iter: 1,
+                    fail_on_nonconvergence: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Failure::fit("coordinate descent did not converge in 1 iterations")
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! # Linear Regression
+//! Ordinary least squares fitted with the normal equations, \\( \hat{\beta} = (X^TX)^{-1}X^Ty \\).
+//! With sample weights the weighted normal equations \\( \hat{\beta} = (X^TWX)^{-1}X^TWy \\) are solved instead,
+//! where \\( W \\) is the diagonal matrix of weights.
+//!
+//! ```
+//! # #[cfg(feature = "datasets")]
+//! # fn main() {
+//! use cora::base::{BaseEstimator, Regressor};
+//! use cora::dataset::make_regression;
+//! use cora::linalg::dense_matrix::DenseMatrix;
+//! use cora::linalg::dense_vector::DenseVector;
+//! use cora::linalg::BaseMatrix;
+//! use cora::linear::linear_regression::LinearRegression;
+//! use cora::metrics::regression::r2_score;
+//!
+//! let (x, y): (DenseMatrix<f64>, DenseVector<f64>) = make_regression(100, 3, 0.1, 42);
+//! let mut targets = DenseMatrix::zeros(100, 1);
+//! targets.set_col(0, &y);
+//!
+//! let lr: LinearRegression<f64, _, DenseVector<f64>> = LinearRegression::default()
+//!     .fit(&x, &targets, Default::default())
+//!     .unwrap();
+//! let y_hat: DenseVector<f64> = lr.predict(&x).unwrap().get_col(0);
+//! assert!(r2_score(&y, &y_hat) > 0.99);
+//! # }
+//! # #[cfg(not(feature = "datasets"))]
+//! # fn main() {}
+//! ```
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Regressor};
+use crate::error::{FailedError, Failure};
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::linear::{linear_predict, prepend_ones};
+use crate::numbers::Real;
+
+/// Linear regression parameters
+#[derive(Clone, Debug)]
+pub struct LinearRegressionParameters<'a, V> {
+    /// Prepend a column of ones to `x` and fit an intercept term.
+    pub fit_intercept: bool,
+    /// Non-negative importance of every training sample, `None` weighs all samples equally.
+    pub sample_weight: Option<&'a V>,
+}
+
+impl<'a, V> Default for LinearRegressionParameters<'a, V> {
+    fn default() -> Self {
+        LinearRegressionParameters {
+            fit_intercept: true,
+            sample_weight: None,
+        }
+    }
+}
+
+/// Ordinary least squares linear regression
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LinearRegression<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> {
+    coefficients: M,
+    intercept: T,
+    _phantom: PhantomData<V>,
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Default for LinearRegression<T, M, V> {
+    fn default() -> Self {
+        LinearRegression {
+            coefficients: M::zeros(0, 1),
+            intercept: T::zero(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> LinearRegression<T, M, V> {
+    /// Fitted coefficients as a column matrix, one row per feature.
+    pub fn coefficients(&self) -> &M {
+        &self.coefficients
+    }
+
+    /// Fitted intercept, zero when `fit_intercept` is off.
+    pub fn intercept(&self) -> T {
+        self.intercept
+    }
+}
+
+impl<'a, T: Real, M: BaseMatrix<T>, V: BaseVector<T>>
+    BaseEstimator<M, LinearRegressionParameters<'a, V>, Failure> for LinearRegression<T, M, V>
+{
+    fn fit(
+        self,
+        x: &M,
+        y: &M,
+        fit_params: LinearRegressionParameters<'a, V>,
+    ) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+
+        if let Some(sample_weight) = fit_params.sample_weight {
+            if sample_weight.len() != nrows {
+                return Err(Failure::invalid_input(&format!(
+                    "sample_weight has {} elements, x has {} samples",
+                    sample_weight.len(),
+                    nrows
+                )));
+            }
+            if let Some(i) = (0..nrows).find(|&i| {
+                let w = sample_weight.get(i);
+                w.is_nan() || w < T::zero()
+            }) {
+                return Err(Failure::invalid_input(&format!(
+                    "sample_weight must be non-negative, got {} at {}",
+                    sample_weight.get(i),
+                    i
+                )));
+            }
+        }
+
+        let design = if fit_params.fit_intercept {
+            prepend_ones(x)
+        } else {
+            x.clone()
+        };
+
+        let mut x_t = design.transpose();
+        if let Some(sample_weight) = fit_params.sample_weight {
+            let (n_coef, _) = x_t.shape();
+            for j in 0..n_coef {
+                for i in 0..nrows {
+                    x_t.set(j, i, x_t.get(j, i) * sample_weight.get(i));
+                }
+            }
+        }
+        let w = x_t
+            .matmul(&design)
+            .inv()
+            .map_err(|e| Failure::with_source(FailedError::SolutionFailed, "X'X is singular", e))?
+            .matmul(&x_t.matmul(y));
+
+        if fit_params.fit_intercept {
+            let mut coefficients = M::zeros(ncols, 1);
+            for j in 0..ncols {
+                coefficients.set(j, 0, w.get(j + 1, 0));
+            }
+            Ok(LinearRegression {
+                coefficients,
+                intercept: w.get(0, 0),
+                _phantom: PhantomData,
+            })
+        } else {
+            Ok(LinearRegression {
+                coefficients: w,
+                intercept: T::zero(),
+                _phantom: PhantomData,
+            })
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Regressor<M, Failure>
+    for LinearRegression<T, M, V>
+{
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        linear_predict(x, &self.coefficients, self.intercept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn fit_predict() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[0.], &[1.], &[2.], &[3.], &[4.]]);
+        let y: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.], &[3.], &[5.], &[7.], &[9.]]);
+
+        let lr: LinearRegression<f64, _, Vec<f64>> = LinearRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        assert!((lr.coefficients().get(0, 0) - 2.).abs() < 1e-6);
+        assert!((lr.intercept() - 1.).abs() < 1e-6);
+
+        let y_hat = lr.predict(&x).unwrap();
+        for i in 0..5 {
+            assert!((y_hat.get(i, 0) - y.get(i, 0)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn fit_singular() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1., 2.], &[2., 4.], &[3., 6.]]);
+        let y: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.], &[2.], &[3.]]);
+
+        let err = LinearRegression::<f64, _, Vec<f64>>::default()
+            .fit(
+                &x,
+                &y,
+                LinearRegressionParameters {
+                    fit_intercept: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Failure::because(FailedError::SolutionFailed, "X'X is singular")
+        );
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn score_on_training_data() {
+        use crate::linalg::dense_matrix::DenseMatrix;
+        use crate::linalg::dense_vector::DenseVector;
+
+        let x =
+            DenseMatrix::from_2d_array(&[&[0., 1.], &[1., 0.], &[2., 3.], &[3., 1.], &[4., 2.]]);
+        let y = DenseMatrix::from_2d_array(&[&[3.], &[2.], &[13.], &[9.], &[14.]]);
+        let lr: LinearRegression<f64, _, DenseVector<f64>> = LinearRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let targets: DenseVector<f64> = y.get_col(0);
+        assert!((lr.score(&x, &targets).unwrap() - 1.).abs() < 1e-9);
+        assert_eq!(
+            lr.score(&x, &DenseVector::new(vec![1., 2.])),
+            Err(Failure::invalid_input("y has 2 samples, x has 5"))
+        );
+    }
+
+    #[test]
+    fn sample_weight_matches_duplicated_rows() {
+        let x: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[0., 1.], &[1., 3.], &[2., 0.], &[3., 2.], &[4., 5.]]);
+        let y: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.], &[4.], &[4.], &[8.], &[7.]]);
+
+        let weighted: LinearRegression<f64, _, Vec<f64>> = LinearRegression::default()
+            .fit(
+                &x,
+                &y,
+                LinearRegressionParameters {
+                    sample_weight: Some(&vec![1., 2., 1., 1., 1.]),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        let mut x_dup = x.clone();
+        x_dup.push(x[1].clone());
+        let mut y_dup = y.clone();
+        y_dup.push(y[1].clone());
+        let duplicated: LinearRegression<f64, _, Vec<f64>> = LinearRegression::default()
+            .fit(&x_dup, &y_dup, Default::default())
+            .unwrap();
+
+        for j in 0..2 {
+            assert!(
+                (weighted.coefficients().get(j, 0) - duplicated.coefficients().get(j, 0)).abs()
+                    < 1e-9
+            );
+        }
+        assert!((weighted.intercept() - duplicated.intercept()).abs() < 1e-9);
+
+        let uniform: LinearRegression<f64, _, Vec<f64>> = LinearRegression::default()
+            .fit(
+                &x,
+                &y,
+                LinearRegressionParameters {
+                    sample_weight: Some(&vec![1.; 5]),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let unweighted: LinearRegression<f64, _, Vec<f64>> = LinearRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        assert_eq!(uniform.coefficients(), unweighted.coefficients());
+        assert_eq!(uniform.intercept(), unweighted.intercept());
+    }
+
+    #[test]
+    fn negative_sample_weight() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[0.], &[1.], &[2.]]);
+        let y: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.], &[3.], &[5.]]);
+
+        let err = LinearRegression::<f64, _, Vec<f64>>::default()
+            .fit(
+                &x,
+                &y,
+                LinearRegressionParameters {
+                    sample_weight: Some(&vec![1., -1., 1.]),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err.error(), FailedError::InvalidInput);
+
+        let err = LinearRegression::<f64, _, Vec<f64>>::default()
+            .fit(
+                &x,
+                &y,
+                LinearRegressionParameters {
+                    sample_weight: Some(&vec![1., f64::NAN, 1.]),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Failure::invalid_input("sample_weight must be non-negative, got NaN at 1")
+        );
+    }
+
+    #[test]
+    fn zero_sample_weights() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[0.], &[1.], &[2.]]);
+        let y: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.], &[3.], &[5.]]);
+
+        let err = LinearRegression::<f64, _, Vec<f64>>::default()
+            .fit(
+                &x,
+                &y,
+                LinearRegressionParameters {
+                    sample_weight: Some(&vec![0., 0., 0.]),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err.error(), FailedError::SolutionFailed);
+    }
+}
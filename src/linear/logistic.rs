@@ -0,0 +1,406 @@
+//! # Logistic Regression
+//! Binary classifier that models \\( P(y = 1 | x) = \sigma(x^Tw + b) \\), fitted with batch or mini-batch
+//! gradient descent on the cross-entropy loss.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Classifier, ProbabilisticClassifier};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Optimization algorithm used to fit the coefficients
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogisticRegressionSolver {
+    /// One update per iteration using the gradient over all samples.
+    GradientDescent,
+    /// Every epoch shuffles the samples with a generator seeded by `seed` and makes one update per batch of
+    /// `batch_size` samples, the last batch may be smaller.
+    MiniBatchSGD {
+        /// Number of samples per update.
+        batch_size: usize,
+        /// Seed of the shuffling generator.
+        seed: u64,
+    },
+}
+
+/// Logistic regression parameters
+#[derive(Clone, Debug)]
+pub struct LogisticRegressionParameters<T: Real> {
+    /// Step size of each gradient descent update.
+    pub learning_rate: T,
+    /// Maximum number of iterations, epochs for [`MiniBatchSGD`](LogisticRegressionSolver::MiniBatchSGD).
+    pub max_iter: usize,
+    /// Stop when the L2 norm of the gradient falls below this value.
+    pub tol: T,
+    /// Return an error instead of the last iterate when `max_iter` is reached without converging.
+    pub fail_on_nonconvergence: bool,
+    /// Optimization algorithm.
+    pub solver: LogisticRegressionSolver,
+}
+
+impl<T: Real> Default for LogisticRegressionParameters<T> {
+    fn default() -> Self {
+        LogisticRegressionParameters {
+            learning_rate: T::from_f64(0.1).unwrap(),
+            max_iter: 1000,
+            tol: T::from_f64(1e-6).unwrap(),
+            fail_on_nonconvergence: false,
+            solver: LogisticRegressionSolver::GradientDescent,
+        }
+    }
+}
+
+/// Binary logistic regression classifier, labels are expected to be 0 or 1.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LogisticRegression<T: Real, M: BaseMatrix<T>> {
+    coefficients: M,
+    intercept: T,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for LogisticRegression<T, M> {
+    fn default() -> Self {
+        LogisticRegression {
+            coefficients: M::zeros(0, 1),
+            intercept: T::zero(),
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> LogisticRegression<T, M> {
+    /// Fitted coefficients as a column matrix, one row per feature.
+    pub fn coefficients(&self) -> &M {
+        &self.coefficients
+    }
+
+    /// Fitted intercept.
+    pub fn intercept(&self) -> T {
+        self.intercept
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, LogisticRegressionParameters<T>, Failure>
+    for LogisticRegression<T, M>
+{
+    fn fit(
+        self,
+        x: &M,
+        y: &M,
+        fit_params: LogisticRegressionParameters<T>,
+    ) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        for i in 0..nrows {
+            let yi = y.get(i, 0);
+            if yi != T::zero() && yi != T::one() {
+                return Err(Failure::fit(&format!(
+                    "labels must be 0 or 1, got {} at row {}",
+                    yi, i
+                )));
+            }
+        }
+
+        if let LogisticRegressionSolver::MiniBatchSGD { batch_size: 0, .. } = fit_params.solver {
+            return Err(Failure::fit("batch_size must be at least 1"));
+        }
+
+        let all: Vec<usize> = (0..nrows).collect();
+        let mut indices = all.clone();
+        let seed = match fit_params.solver {
+            LogisticRegressionSolver::MiniBatchSGD { seed, .. } => seed,
+            LogisticRegressionSolver::GradientDescent => 0,
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut w = M::zeros(ncols, 1);
+        let mut b = T::zero();
+
+        for _ in 0..fit_params.max_iter {
+            let (grad_w, grad_b) = gradient(x, y, &w, b, &all);
+            let grad_norm = grad_w
+                .iter()
+                .fold(grad_b.square(), |acc, &g| acc + g.square());
+            if grad_norm.sqrt() < fit_params.tol {
+                return Ok(LogisticRegression {
+                    coefficients: w,
+                    intercept: b,
+                });
+            }
+
+            match fit_params.solver {
+                LogisticRegressionSolver::GradientDescent => {
+                    step(&mut w, &mut b, &grad_w, grad_b, fit_params.learning_rate)
+                }
+                LogisticRegressionSolver::MiniBatchSGD { batch_size, .. } => {
+                    indices.shuffle(&mut rng);
+                    for batch in indices.chunks(batch_size) {
+                        let (grad_w, grad_b) = gradient(x, y, &w, b, batch);
+                        step(&mut w, &mut b, &grad_w, grad_b, fit_params.learning_rate);
+                    }
+                }
+            }
+        }
+
+        if fit_params.fail_on_nonconvergence {
+            return Err(Failure::fit(&format!(
+                "gradient descent did not converge in {} iterations",
+                fit_params.max_iter
+            )));
+        }
+
+        Ok(LogisticRegression {
+            coefficients: w,
+            intercept: b,
+        })
+    }
+}
+
+/// Gradient of the mean cross-entropy over the rows in `rows`, with respect to `w` and `b`.
+fn gradient<T: Real, M: BaseMatrix<T>>(x: &M, y: &M, w: &M, b: T, rows: &[usize]) -> (Vec<T>, T) {
+    let (_, ncols) = x.shape();
+    let n = T::from_usize(rows.len()).unwrap();
+    let mut grad_w = vec![T::zero(); ncols];
+    let mut grad_b = T::zero();
+    for &i in rows {
+        let z = (0..ncols).fold(b, |acc, j| acc + x.get(i, j) * w.get(j, 0));
+        let err = z.sigmoid() - y.get(i, 0);
+        grad_b += err;
+        for (j, g) in grad_w.iter_mut().enumerate() {
+            *g += err * x.get(i, j);
+        }
+    }
+    for g in grad_w.iter_mut() {
+        *g /= n;
+    }
+    (grad_w, grad_b / n)
+}
+
+/// Move `w` and `b` one step of size `learning_rate` against the gradient.
+fn step<T: Real, M: BaseMatrix<T>>(
+    w: &mut M,
+    b: &mut T,
+    grad_w: &[T],
+    grad_b: T,
+    learning_rate: T,
+) {
+    for (j, &g) in grad_w.iter().enumerate() {
+        w.set(j, 0, w.get(j, 0) - learning_rate * g);
+    }
+    *b -= learning_rate * grad_b;
+}
+
+impl<T: Real, M: BaseMatrix<T>> LogisticRegression<T, M> {
+    /// \\( P(y = 1 | x) \\) for every row of `x`, as a column matrix.
+    fn positive_proba(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (n_coef, _) = self.coefficients.shape();
+        if ncols != n_coef {
+            return Err(Failure::predict(&format!(
+                "x has {} features, model was fitted with {}",
+                ncols, n_coef
+            )));
+        }
+        let mut p = x.matmul(&self.coefficients);
+        for i in 0..nrows {
+            p.set(i, 0, (p.get(i, 0) + self.intercept).sigmoid());
+        }
+        Ok(p)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Classifier<M, Failure> for LogisticRegression<T, M> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        let p = self.positive_proba(x)?;
+        let (nrows, _) = p.shape();
+        let mut labels = M::zeros(nrows, 1);
+        for i in 0..nrows {
+            // Ties go to label 0, matching the argmax of `predict_proba`.
+            if p.get(i, 0) > T::half() {
+                labels.set(i, 0, T::one());
+            }
+        }
+        Ok(labels)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> ProbabilisticClassifier<M, Failure> for LogisticRegression<T, M> {
+    /// Two columns, `[1 - p, p]` where `p` is the probability of label 1.
+    fn predict_proba(&self, x: &M) -> Result<M, Failure> {
+        let p = self.positive_proba(x)?;
+        let (nrows, _) = p.shape();
+        let mut proba = M::zeros(nrows, 2);
+        for i in 0..nrows {
+            proba.set(i, 0, T::one() - p.get(i, 0));
+            proba.set(i, 1, p.get(i, 0));
+        }
+        Ok(proba)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data() -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let x = BaseMatrix::from_2d_array(&[
+            &[1., 1.],
+            &[1.5, 2.],
+            &[2., 1.],
+            &[2., 0.5],
+            &[4., 4.],
+            &[5., 3.5],
+            &[4.5, 5.],
+            &[3.5, 4.5],
+        ]);
+        let y =
+            BaseMatrix::from_2d_array(&[&[0.], &[0.], &[0.], &[0.], &[1.], &[1.], &[1.], &[1.]]);
+        (x, y)
+    }
+
+    #[test]
+    fn fit_predict_separable() {
+        let (x, y) = data();
+        let lr = LogisticRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        assert_eq!(lr.predict(&x).unwrap(), y);
+    }
+
+    #[test]
+    fn fail_on_nonconvergence() {
+        let (x, y) = data();
+        let err = LogisticRegression::default()
+            .fit(
+                &x,
+                &y,
+                LogisticRegressionParameters {
+                    max_iter: 5,
+                    fail_on_nonconvergence: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            Failure::fit("gradient descent did not converge in 5 iterations")
+        );
+    }
+
+    fn accuracy(y: &[Vec<f64>], y_hat: &[Vec<f64>]) -> f64 {
+        let hits = y.iter().zip(y_hat.iter()).filter(|(a, b)| a == b).count();
+        hits as f64 / y.len() as f64
+    }
+
+    #[test]
+    fn mini_batch_sgd_matches_gradient_descent() {
+        let (x, y) = data();
+        let gd = LogisticRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        let sgd_params = LogisticRegressionParameters {
+            max_iter: 200,
+            solver: LogisticRegressionSolver::MiniBatchSGD {
+                batch_size: 3,
+                seed: 7,
+            },
+            ..Default::default()
+        };
+        let sgd = LogisticRegression::default()
+            .fit(&x, &y, sgd_params.clone())
+            .unwrap();
+
+        assert_eq!(accuracy(&y, &gd.predict(&x).unwrap()), 1.);
+        assert_eq!(accuracy(&y, &sgd.predict(&x).unwrap()), 1.);
+
+        let again = LogisticRegression::default()
+            .fit(&x, &y, sgd_params)
+            .unwrap();
+        assert_eq!(sgd.coefficients(), again.coefficients());
+        assert_eq!(sgd.intercept(), again.intercept());
+    }
+
+    #[test]
+    fn full_batch_epoch_matches_gradient_descent() {
+        let (x, y) = data();
+        let fit = |solver| {
+            LogisticRegression::default()
+                .fit(
+                    &x,
+                    &y,
+                    LogisticRegressionParameters {
+                        max_iter: 1,
+                        solver,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+        };
+        let gd = fit(LogisticRegressionSolver::GradientDescent);
+        let sgd = fit(LogisticRegressionSolver::MiniBatchSGD {
+            batch_size: 8,
+            seed: 0,
+        });
+
+        for j in 0..2 {
+            assert!((gd.coefficients().get(j, 0) - sgd.coefficients().get(j, 0)).abs() < 1e-12);
+        }
+        assert!((gd.intercept() - sgd.intercept()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn score_is_accuracy() {
+        let (x, y) = data();
+        let lr = LogisticRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let mut labels: Vec<f64> = y.get_col(0);
+        assert_eq!(lr.score(&x, &labels).unwrap(), 1.);
+        labels[0] = 1.;
+        assert_eq!(lr.score(&x, &labels).unwrap(), 0.875);
+    }
+
+    #[test]
+    fn predict_proba_matches_predict() {
+        use crate::linalg::BaseVector;
+
+        let (x, y) = data();
+        let lr = LogisticRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let proba = lr.predict_proba(&x).unwrap();
+        let labels = lr.predict(&x).unwrap();
+        for i in 0..x.len() {
+            let row: Vec<f64> = proba.get_row(i);
+            assert!((row.sum() - 1.).abs() < 1e-12);
+            assert_eq!(row.argmax() as f64, labels.get(i, 0));
+        }
+    }
+
+    #[test]
+    fn decision_boundary_tie() {
+        use crate::linalg::BaseVector;
+
+        let lr: LogisticRegression<f64, Vec<Vec<f64>>> = LogisticRegression {
+            coefficients: BaseMatrix::from_2d_array(&[&[1.], &[-1.]]),
+            intercept: 0.,
+        };
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[2., 2.]]);
+
+        let proba: Vec<f64> = lr.predict_proba(&x).unwrap().get_row(0);
+        assert_eq!(proba, vec![0.5, 0.5]);
+        assert_eq!(lr.predict(&x).unwrap(), vec![vec![0.]]);
+        assert_eq!(proba.argmax(), 0);
+    }
+}
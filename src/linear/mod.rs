@@ -0,0 +1,133 @@
+//! # Linear Models
+//! Estimators that model the target as a linear combination of the input features.
+
+pub mod elastic_net;
+pub mod lasso;
+pub mod linear_regression;
+pub mod logistic;
+pub mod perceptron;
+pub mod ridge;
+
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Return `x` with a leading column of ones, used to fit an intercept term.
+pub(crate) fn prepend_ones<T: Real, M: BaseMatrix<T>>(x: &M) -> M {
+    let (nrows, ncols) = x.shape();
+    let mut design = M::ones(nrows, ncols + 1);
+    for i in 0..nrows {
+        for j in 0..ncols {
+            design.set(i, j + 1, x.get(i, j));
+        }
+    }
+    design
+}
+
+/// Predict `x·w + b` for a fitted linear model with column coefficients `w` and intercept `b`, failing when `x` does not
+/// have one column per coefficient.
+pub(crate) fn linear_predict<T: Real, M: BaseMatrix<T>>(
+    x: &M,
+    coefficients: &M,
+    intercept: T,
+) -> Result<M, Failure> {
+    let (nrows, ncols) = x.shape();
+    let (n_coef, _) = coefficients.shape();
+    if ncols != n_coef {
+        return Err(Failure::predict(&format!(
+            "x has {} features, model was fitted with {}",
+            ncols, n_coef
+        )));
+    }
+    let mut y_hat = x.matmul(coefficients);
+    for i in 0..nrows {
+        y_hat.set(i, 0, y_hat.get(i, 0) + intercept);
+    }
+    Ok(y_hat)
+}
+
+/// Minimize \\( \frac{1}{2n}\lVert y - Xw - b \rVert^2 + l_1 \lVert w \rVert_1 + \frac{l_2}{2} \lVert w \rVert_2^2 \\) with cyclic
+/// coordinate descent on centered data. Returns the coefficients as a column matrix, the intercept and whether no
+/// coefficient moved by more than `tol` during the last pass.
+pub(crate) fn coordinate_descent<T: Real, M: BaseMatrix<T>>(
+    x: &M,
+    y: &M,
+    l1: T,
+    l2: T,
+    fit_intercept: bool,
+    max_iter: usize,
+    tol: T,
+) -> (M, T, bool) {
+    let (nrows, ncols) = x.shape();
+    let n = T::from_usize(nrows).unwrap();
+    let mut x_mean = vec![T::zero(); ncols];
+    let mut y_mean = T::zero();
+    if fit_intercept {
+        for i in 0..nrows {
+            for (j, mu) in x_mean.iter_mut().enumerate() {
+                *mu += x.get(i, j) / n;
+            }
+            y_mean += y.get(i, 0) / n;
+        }
+    }
+    let mut xc = M::zeros(nrows, ncols);
+    for i in 0..nrows {
+        for (j, &mu) in x_mean.iter().enumerate() {
+            xc.set(i, j, x.get(i, j) - mu);
+        }
+    }
+    let col_sq: Vec<T> = (0..ncols)
+        .map(|j| (0..nrows).map(|i| xc.get(i, j).square()).sum::<T>() / n)
+        .collect();
+
+    let mut w = vec![T::zero(); ncols];
+    let mut residual: Vec<T> = (0..nrows).map(|i| y.get(i, 0) - y_mean).collect();
+    let mut converged = false;
+    for _ in 0..max_iter {
+        let mut max_change = T::zero();
+        for j in 0..ncols {
+            let w_old = w[j];
+            let mut rho = T::zero();
+            for (i, &r) in residual.iter().enumerate() {
+                rho += xc.get(i, j) * (r + xc.get(i, j) * w_old);
+            }
+            rho /= n;
+            let denom = col_sq[j] + l2;
+            w[j] = if denom > T::zero() {
+                soft_threshold(rho, l1) / denom
+            } else {
+                T::zero()
+            };
+            let delta = w[j] - w_old;
+            if delta != T::zero() {
+                for (i, r) in residual.iter_mut().enumerate() {
+                    *r -= xc.get(i, j) * delta;
+                }
+            }
+            max_change = max_change.max(delta.abs());
+        }
+        if max_change <= tol {
+            converged = true;
+            break;
+        }
+    }
+
+    let mut coefficients = M::zeros(ncols, 1);
+    let mut intercept = y_mean;
+    for j in 0..ncols {
+        coefficients.set(j, 0, w[j]);
+        intercept -= x_mean[j] * w[j];
+    }
+    (coefficients, intercept, converged)
+}
+
+/// \\( S(x, \lambda) = \operatorname{sign}(x) \max(|x| - \lambda, 0) \\)
+fn soft_threshold<T: Real>(x: T, lambda: T) -> T {
+    if x > lambda {
+        x - lambda
+    } else if x < -lambda {
+        x + lambda
+    } else {
+        T::zero()
+    }
+}
@@ -0,0 +1,198 @@
+//! # Perceptron
+//! Binary linear classifier fitted with the classic [perceptron](https://en.wikipedia.org/wiki/Perceptron) rule:
+//! every misclassified sample moves the weights by \\( \eta (y - \hat{y}) x \\).
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Classifier};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Perceptron parameters
+#[derive(Clone, Debug)]
+pub struct PerceptronParameters<T: Real> {
+    /// Step size of each update.
+    pub learning_rate: T,
+    /// Maximum number of passes over the training data.
+    pub max_iter: usize,
+}
+
+impl<T: Real> Default for PerceptronParameters<T> {
+    fn default() -> Self {
+        PerceptronParameters {
+            learning_rate: T::one(),
+            max_iter: 1000,
+        }
+    }
+}
+
+/// Binary perceptron classifier, labels are expected to be 0 or 1.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Perceptron<T: Real, M: BaseMatrix<T>> {
+    coefficients: M,
+    intercept: T,
+    updates_per_epoch: Vec<usize>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for Perceptron<T, M> {
+    fn default() -> Self {
+        Perceptron {
+            coefficients: M::zeros(0, 1),
+            intercept: T::zero(),
+            updates_per_epoch: Vec::new(),
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Perceptron<T, M> {
+    /// Fitted coefficients as a column matrix, one row per feature.
+    pub fn coefficients(&self) -> &M {
+        &self.coefficients
+    }
+
+    /// Fitted intercept.
+    pub fn intercept(&self) -> T {
+        self.intercept
+    }
+
+    /// Number of weight updates made in each epoch. Training converged if the last entry is zero.
+    pub fn updates_per_epoch(&self) -> &[usize] {
+        &self.updates_per_epoch
+    }
+
+    fn decide(&self, x: &M, i: usize) -> T {
+        let (_, ncols) = x.shape();
+        let z = (0..ncols).fold(self.intercept, |acc, j| {
+            acc + x.get(i, j) * self.coefficients.get(j, 0)
+        });
+        if z > T::zero() {
+            T::one()
+        } else {
+            T::zero()
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, PerceptronParameters<T>, Failure>
+    for Perceptron<T, M>
+{
+    fn fit(self, x: &M, y: &M, fit_params: PerceptronParameters<T>) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        for i in 0..nrows {
+            let yi = y.get(i, 0);
+            if yi != T::zero() && yi != T::one() {
+                return Err(Failure::fit(&format!(
+                    "labels must be 0 or 1, got {} at row {}",
+                    yi, i
+                )));
+            }
+        }
+        if fit_params.learning_rate <= T::zero() {
+            return Err(Failure::fit(&format!(
+                "learning_rate must be positive, got {}",
+                fit_params.learning_rate
+            )));
+        }
+
+        let mut model = Perceptron {
+            coefficients: M::zeros(ncols, 1),
+            intercept: T::zero(),
+            updates_per_epoch: Vec::new(),
+        };
+        for _ in 0..fit_params.max_iter {
+            let mut updates = 0;
+            for i in 0..nrows {
+                let delta = fit_params.learning_rate * (y.get(i, 0) - model.decide(x, i));
+                if delta != T::zero() {
+                    for j in 0..ncols {
+                        let w = model.coefficients.get(j, 0);
+                        model.coefficients.set(j, 0, w + delta * x.get(i, j));
+                    }
+                    model.intercept += delta;
+                    updates += 1;
+                }
+            }
+            model.updates_per_epoch.push(updates);
+            if updates == 0 {
+                break;
+            }
+        }
+        Ok(model)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Classifier<M, Failure> for Perceptron<T, M> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (n_coef, _) = self.coefficients.shape();
+        if ncols != n_coef {
+            return Err(Failure::predict(&format!(
+                "x has {} features, model was fitted with {}",
+                ncols, n_coef
+            )));
+        }
+        let mut labels = M::zeros(nrows, 1);
+        for i in 0..nrows {
+            labels.set(i, 0, self.decide(x, i));
+        }
+        Ok(labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+
+    #[test]
+    fn separable_data_converges() {
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 1.],
+            &[1.5, 2.],
+            &[2., 1.],
+            &[2., 0.5],
+            &[4., 4.],
+            &[5., 3.5],
+            &[4.5, 5.],
+            &[3.5, 4.5],
+        ]);
+        let y =
+            DenseMatrix::from_2d_array(&[&[0.], &[0.], &[0.], &[0.], &[1.], &[1.], &[1.], &[1.]]);
+        let p = Perceptron::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        assert_eq!(p.predict(&x).unwrap(), y);
+        let updates = p.updates_per_epoch();
+        assert_eq!(updates.last(), Some(&0));
+        assert!(updates.len() < 1000);
+        assert!(updates[0] > 0);
+    }
+
+    #[test]
+    fn non_separable_runs_all_epochs() {
+        let x = DenseMatrix::from_2d_array(&[&[0., 0.], &[0., 1.], &[1., 0.], &[1., 1.]]);
+        let y = DenseMatrix::from_2d_array(&[&[0.], &[1.], &[1.], &[0.]]);
+        let p = Perceptron::default()
+            .fit(
+                &x,
+                &y,
+                PerceptronParameters {
+                    max_iter: 20,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(p.updates_per_epoch().len(), 20);
+        assert!(p.updates_per_epoch().iter().all(|&u| u > 0));
+    }
+}
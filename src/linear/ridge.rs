@@ -0,0 +1,182 @@
+//! # Ridge Regression
+//! Least squares with an L2 penalty on the coefficients, \\( \hat{\beta} = (X^TX + \alpha I)^{-1}X^Ty \\).
+//! The intercept, when fitted, is not penalized.
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Regressor};
+use crate::error::{FailedError, Failure};
+use crate::linalg::BaseMatrix;
+use crate::linear::{linear_predict, prepend_ones};
+use crate::numbers::Real;
+
+/// Ridge regression parameters
+#[derive(Clone, Debug)]
+pub struct RidgeRegressionParameters<T: Real> {
+    /// Regularization strength, must be non-negative.
+    pub alpha: T,
+    /// Prepend a column of ones to `x` and fit an intercept term.
+    pub fit_intercept: bool,
+}
+
+impl<T: Real> Default for RidgeRegressionParameters<T> {
+    fn default() -> Self {
+        RidgeRegressionParameters {
+            alpha: T::one(),
+            fit_intercept: true,
+        }
+    }
+}
+
+/// Linear regression with L2 regularization
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RidgeRegression<T: Real, M: BaseMatrix<T>> {
+    coefficients: M,
+    intercept: T,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for RidgeRegression<T, M> {
+    fn default() -> Self {
+        RidgeRegression {
+            coefficients: M::zeros(0, 1),
+            intercept: T::zero(),
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> RidgeRegression<T, M> {
+    /// Fitted coefficients as a column matrix, one row per feature.
+    pub fn coefficients(&self) -> &M {
+        &self.coefficients
+    }
+
+    /// Fitted intercept, zero when `fit_intercept` is off.
+    pub fn intercept(&self) -> T {
+        self.intercept
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, RidgeRegressionParameters<T>, Failure>
+    for RidgeRegression<T, M>
+{
+    fn fit(self, x: &M, y: &M, fit_params: RidgeRegressionParameters<T>) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if fit_params.alpha < T::zero() {
+            return Err(Failure::fit(&format!(
+                "alpha must be non-negative, got {}",
+                fit_params.alpha
+            )));
+        }
+
+        let (design, offset) = if fit_params.fit_intercept {
+            (prepend_ones(x), 1)
+        } else {
+            (x.clone(), 0)
+        };
+
+        let x_t = design.transpose();
+        let mut x_t_x = x_t.matmul(&design);
+        for j in offset..(ncols + offset) {
+            x_t_x.set(j, j, x_t_x.get(j, j) + fit_params.alpha);
+        }
+
+        let w = x_t_x
+            .inv()
+            .map_err(|e| {
+                Failure::with_source(FailedError::SolutionFailed, "X'X + alpha*I is singular", e)
+            })?
+            .matmul(&x_t.matmul(y));
+
+        let mut coefficients = M::zeros(ncols, 1);
+        for j in 0..ncols {
+            coefficients.set(j, 0, w.get(j + offset, 0));
+        }
+        let intercept = if fit_params.fit_intercept {
+            w.get(0, 0)
+        } else {
+            T::zero()
+        };
+
+        Ok(RidgeRegression {
+            coefficients,
+            intercept,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Regressor<M, Failure> for RidgeRegression<T, M> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        linear_predict(x, &self.coefficients, self.intercept)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linear::linear_regression::LinearRegression;
+
+    fn data() -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let x = BaseMatrix::from_2d_array(&[
+            &[1., 2.],
+            &[2., 1.],
+            &[3., 5.],
+            &[4., 3.],
+            &[5., 8.],
+            &[6., 4.],
+        ]);
+        let y = BaseMatrix::from_2d_array(&[&[6.1], &[5.9], &[13.2], &[11.8], &[21.1], &[15.9]]);
+        (x, y)
+    }
+
+    #[test]
+    fn alpha_zero_matches_ols() {
+        let (x, y) = data();
+        let ridge = RidgeRegression::default()
+            .fit(
+                &x,
+                &y,
+                RidgeRegressionParameters {
+                    alpha: 0.,
+                    fit_intercept: true,
+                },
+            )
+            .unwrap();
+        let ols: LinearRegression<f64, _, Vec<f64>> = LinearRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        for j in 0..2 {
+            assert!((ridge.coefficients().get(j, 0) - ols.coefficients().get(j, 0)).abs() < 1e-8);
+        }
+        assert!((ridge.intercept() - ols.intercept()).abs() < 1e-8);
+    }
+
+    #[test]
+    fn alpha_shrinks_coefficients() {
+        let (x, y) = data();
+        let mut prev_norm = f64::INFINITY;
+        for &alpha in &[0., 0.1, 1., 10., 100.] {
+            let ridge = RidgeRegression::default()
+                .fit(
+                    &x,
+                    &y,
+                    RidgeRegressionParameters {
+                        alpha,
+                        fit_intercept: true,
+                    },
+                )
+                .unwrap();
+            let c = ridge.coefficients();
+            let norm = (c.get(0, 0).powi(2) + c.get(1, 0).powi(2)).sqrt();
+            assert!(norm < prev_norm);
+            prev_norm = norm;
+        }
+    }
+}
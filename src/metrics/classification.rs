@@ -0,0 +1,299 @@
+//! # Classification Metrics
+//! Functions that score predicted labels against ground truth.
+//! Binary metrics expect labels encoded as 0 (negative) and 1 (positive).
+
+use crate::error::Failure;
+use crate::linalg::BaseVector;
+use crate::metrics::check_lengths;
+use crate::numbers::Real;
+
+/// Fraction of predictions that match the true labels.
+pub fn accuracy<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> T {
+    check_lengths(y_true, y_pred);
+    let mut correct = 0;
+    for i in 0..y_true.len() {
+        if y_true.get(i) == y_pred.get(i) {
+            correct += 1;
+        }
+    }
+    T::from_usize(correct).unwrap() / T::from_usize(y_true.len()).unwrap()
+}
+
+/// Binary confusion matrix, rows are true labels and columns are predicted labels:
+/// `[[TN, FP], [FN, TP]]`.
+pub fn confusion_matrix<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> [[usize; 2]; 2] {
+    check_lengths(y_true, y_pred);
+    let mut cm = [[0; 2]; 2];
+    for i in 0..y_true.len() {
+        let t = usize::from(y_true.get(i) == T::one());
+        let p = usize::from(y_pred.get(i) == T::one());
+        cm[t][p] += 1;
+    }
+    cm
+}
+
+fn ratio<T: Real>(num: usize, den: usize) -> T {
+    if den == 0 {
+        T::zero()
+    } else {
+        T::from_usize(num).unwrap() / T::from_usize(den).unwrap()
+    }
+}
+
+/// [Precision](https://en.wikipedia.org/wiki/Precision_and_recall), \\( \frac{TP}{TP + FP} \\).
+/// Defined as zero when there are no positive predictions.
+pub fn precision<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> T {
+    let cm = confusion_matrix(y_true, y_pred);
+    ratio(cm[1][1], cm[1][1] + cm[0][1])
+}
+
+/// [Recall](https://en.wikipedia.org/wiki/Precision_and_recall), \\( \frac{TP}{TP + FN} \\).
+/// Defined as zero when there are no positive labels.
+pub fn recall<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> T {
+    let cm = confusion_matrix(y_true, y_pred);
+    ratio(cm[1][1], cm[1][1] + cm[1][0])
+}
+
+/// [F1 score](https://en.wikipedia.org/wiki/F-score), the harmonic mean of precision and recall.
+/// Defined as zero when both precision and recall are zero.
+pub fn f1_score<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> T {
+    let p: T = precision(y_true, y_pred);
+    let r: T = recall(y_true, y_pred);
+    if p + r == T::zero() {
+        T::zero()
+    } else {
+        T::two() * p * r / (p + r)
+    }
+}
+
+/// Multiclass confusion matrix for labels `0..n_classes`, entry `[t][p]` counts samples of true class `t` predicted
+/// as `p`. Fails with `FailedError::InvalidInput` if a label is not an integer in that range.
+pub fn confusion_matrix_multi<T: Real, V: BaseVector<T>>(
+    y_true: &V,
+    y_pred: &V,
+    n_classes: usize,
+) -> Result<Vec<Vec<usize>>, Failure> {
+    check_lengths(y_true, y_pred);
+    let class_of = |label: T, i: usize| {
+        label
+            .to_usize()
+            .filter(|&c| c < n_classes && T::from_usize(c).unwrap() == label)
+            .ok_or_else(|| {
+                Failure::invalid_input(&format!(
+                    "label {} at index {} is not an integer in [0, {})",
+                    label, i, n_classes
+                ))
+            })
+    };
+    let mut cm = vec![vec![0; n_classes]; n_classes];
+    for i in 0..y_true.len() {
+        cm[class_of(y_true.get(i), i)?][class_of(y_pred.get(i), i)?] += 1;
+    }
+    Ok(cm)
+}
+
+/// Per-class precision and recall from a multiclass confusion matrix, zero for classes without predictions or
+/// without samples respectively.
+fn per_class<T: Real>(cm: &[Vec<usize>]) -> Vec<(T, T)> {
+    (0..cm.len())
+        .map(|c| {
+            let tp = cm[c][c];
+            let predicted: usize = cm.iter().map(|row| row[c]).sum();
+            let actual: usize = cm[c].iter().sum();
+            (ratio(tp, predicted), ratio(tp, actual))
+        })
+        .collect()
+}
+
+fn macro_average<T: Real, F: Fn((T, T)) -> T>(cm: &[Vec<usize>], score: F) -> T {
+    let scores = per_class(cm);
+    let n = T::from_usize(scores.len()).unwrap();
+    scores.into_iter().map(score).sum::<T>() / n
+}
+
+/// Unweighted mean of the per-class precisions over labels `0..n_classes`, see [`confusion_matrix_multi`].
+pub fn precision_macro<T: Real, V: BaseVector<T>>(
+    y_true: &V,
+    y_pred: &V,
+    n_classes: usize,
+) -> Result<T, Failure> {
+    let cm = confusion_matrix_multi(y_true, y_pred, n_classes)?;
+    Ok(macro_average(&cm, |(p, _)| p))
+}
+
+/// Unweighted mean of the per-class recalls over labels `0..n_classes`, see [`confusion_matrix_multi`].
+pub fn recall_macro<T: Real, V: BaseVector<T>>(
+    y_true: &V,
+    y_pred: &V,
+    n_classes: usize,
+) -> Result<T, Failure> {
+    let cm = confusion_matrix_multi(y_true, y_pred, n_classes)?;
+    Ok(macro_average(&cm, |(_, r)| r))
+}
+
+/// Unweighted mean of the per-class F1 scores over labels `0..n_classes`, see [`confusion_matrix_multi`].
+pub fn f1_macro<T: Real, V: BaseVector<T>>(
+    y_true: &V,
+    y_pred: &V,
+    n_classes: usize,
+) -> Result<T, Failure> {
+    let cm = confusion_matrix_multi(y_true, y_pred, n_classes)?;
+    Ok(macro_average(&cm, |(p, r)| {
+        if p + r == T::zero() {
+            T::zero()
+        } else {
+            T::two() * p * r / (p + r)
+        }
+    }))
+}
+
+/// Precision and recall of the true positives, false positives and false negatives pooled over all classes.
+fn micro_average<T: Real>(cm: &[Vec<usize>]) -> (T, T) {
+    let tp: usize = (0..cm.len()).map(|c| cm[c][c]).sum();
+    let predicted: usize = (0..cm.len())
+        .map(|c| cm.iter().map(|row| row[c]).sum::<usize>())
+        .sum();
+    let actual: usize = cm.iter().map(|row| row.iter().sum::<usize>()).sum();
+    (ratio(tp, predicted), ratio(tp, actual))
+}
+
+/// Precision of the counts pooled over labels `0..n_classes`, see [`confusion_matrix_multi`].
+pub fn precision_micro<T: Real, V: BaseVector<T>>(
+    y_true: &V,
+    y_pred: &V,
+    n_classes: usize,
+) -> Result<T, Failure> {
+    let cm = confusion_matrix_multi(y_true, y_pred, n_classes)?;
+    Ok(micro_average::<T>(&cm).0)
+}
+
+/// Recall of the counts pooled over labels `0..n_classes`, see [`confusion_matrix_multi`].
+pub fn recall_micro<T: Real, V: BaseVector<T>>(
+    y_true: &V,
+    y_pred: &V,
+    n_classes: usize,
+) -> Result<T, Failure> {
+    let cm = confusion_matrix_multi(y_true, y_pred, n_classes)?;
+    Ok(micro_average::<T>(&cm).1)
+}
+
+/// F1 score of the micro-averaged precision and recall over labels `0..n_classes`, see [`confusion_matrix_multi`].
+pub fn f1_micro<T: Real, V: BaseVector<T>>(
+    y_true: &V,
+    y_pred: &V,
+    n_classes: usize,
+) -> Result<T, Failure> {
+    let cm = confusion_matrix_multi(y_true, y_pred, n_classes)?;
+    let (p, r): (T, T) = micro_average(&cm);
+    Ok(if p + r == T::zero() {
+        T::zero()
+    } else {
+        T::two() * p * r / (p + r)
+    })
+}
+
+/// Usual value of the `eps` argument of [`log_loss`].
+pub const LOG_LOSS_EPS: f64 = 1e-15;
+
+/// Binary [cross-entropy](https://en.wikipedia.org/wiki/Cross-entropy),
+/// \\( -\frac{1}{n} \sum_i y_i \ln p_i + (1 - y_i) \ln(1 - p_i) \\), between 0/1 labels and predicted probabilities of
+/// the positive class. Probabilities are clipped to `[eps, 1 - eps]` so the loss stays finite, see [`LOG_LOSS_EPS`].
+pub fn log_loss<T: Real, V: BaseVector<T>>(y_true: &V, y_prob: &V, eps: T) -> T {
+    check_lengths(y_true, y_prob);
+    let p = y_prob.clip(eps, T::one() - eps);
+    let mut sum = T::zero();
+    for i in 0..y_true.len() {
+        let y = y_true.get(i);
+        sum -= y * p.get(i).ln() + (T::one() - y) * (T::one() - p.get(i)).ln();
+    }
+    sum / T::from_usize(y_true.len()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_metrics() {
+        // TP = 3, FP = 1, FN = 2, TN = 4
+        let y_true: Vec<f64> = vec![1., 1., 1., 1., 1., 0., 0., 0., 0., 0.];
+        let y_pred: Vec<f64> = vec![1., 1., 1., 0., 0., 1., 0., 0., 0., 0.];
+
+        assert_eq!(confusion_matrix(&y_true, &y_pred), [[4, 1], [2, 3]]);
+        assert_eq!(accuracy::<f64, _>(&y_true, &y_pred), 0.7);
+        assert_eq!(precision::<f64, _>(&y_true, &y_pred), 0.75);
+        assert_eq!(recall::<f64, _>(&y_true, &y_pred), 0.6);
+        assert!((f1_score::<f64, _>(&y_true, &y_pred) - 2. / 3.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn no_positives() {
+        let y_true: Vec<f64> = vec![0., 0., 0.];
+        let y_pred: Vec<f64> = vec![0., 0., 0.];
+        assert_eq!(precision::<f64, _>(&y_true, &y_pred), 0.);
+        assert_eq!(recall::<f64, _>(&y_true, &y_pred), 0.);
+        assert_eq!(f1_score::<f64, _>(&y_true, &y_pred), 0.);
+    }
+
+    #[test]
+    fn log_loss() {
+        let y_true: Vec<f64> = vec![1., 0., 1., 0.];
+        assert!(super::log_loss(&y_true, &y_true, LOG_LOSS_EPS) < 1e-12);
+
+        let wrong: Vec<f64> = vec![0., 1., 0., 1.];
+        assert!(super::log_loss(&y_true, &wrong, LOG_LOSS_EPS) > 30.);
+
+        let y_prob: Vec<f64> = vec![0.9, 0.2, 0.6, 0.5];
+        let expected = -(0.9f64.ln() + 0.8f64.ln() + 0.6f64.ln() + 0.5f64.ln()) / 4.;
+        assert!((super::log_loss(&y_true, &y_prob, LOG_LOSS_EPS) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch (y_true=2, y_pred=1)")]
+    fn log_loss_length_mismatch() {
+        super::log_loss(&vec![1f64, 0.], &vec![0.5], LOG_LOSS_EPS);
+    }
+
+    #[test]
+    fn multiclass_metrics() {
+        let y_true: Vec<f64> = vec![0., 0., 0., 1., 1., 1., 1., 2., 2., 2.];
+        let y_pred: Vec<f64> = vec![0., 0., 1., 1., 1., 1., 2., 2., 0., 0.];
+
+        let cm = confusion_matrix_multi(&y_true, &y_pred, 3).unwrap();
+        assert_eq!(cm, vec![vec![2, 1, 0], vec![0, 3, 1], vec![2, 0, 1]]);
+
+        // per class precision 2/4, 3/4, 1/2 and recall 2/3, 3/4, 1/3
+        let p: f64 = precision_macro(&y_true, &y_pred, 3).unwrap();
+        let r: f64 = recall_macro(&y_true, &y_pred, 3).unwrap();
+        let f1: f64 = f1_macro(&y_true, &y_pred, 3).unwrap();
+        assert!((p - (0.5 + 0.75 + 0.5) / 3.).abs() < 1e-12);
+        assert!((r - (2. / 3. + 0.75 + 1. / 3.) / 3.).abs() < 1e-12);
+        assert!((f1 - (4. / 7. + 0.75 + 0.4) / 3.).abs() < 1e-12);
+
+        // class 3 has no samples and no predictions and counts as zero
+        let p4: f64 = precision_macro(&y_true, &y_pred, 4).unwrap();
+        assert!((p4 - p * 3. / 4.).abs() < 1e-12);
+
+        // pooled TP = 6, FP = FN = 4
+        let p: f64 = precision_micro(&y_true, &y_pred, 3).unwrap();
+        let r: f64 = recall_micro(&y_true, &y_pred, 3).unwrap();
+        let f1: f64 = f1_micro(&y_true, &y_pred, 3).unwrap();
+        assert!((p - 0.6).abs() < 1e-12);
+        assert!((r - 0.6).abs() < 1e-12);
+        assert!((f1 - 0.6).abs() < 1e-12);
+        assert!(f1_micro::<f64, _>(&y_true, &y_pred, 2).is_err());
+    }
+
+    #[test]
+    fn multiclass_invalid_labels() {
+        let y_true: Vec<f64> = vec![0., 1.];
+        assert_eq!(
+            confusion_matrix_multi(&y_true, &vec![0., 3.], 3),
+            Err(Failure::invalid_input(
+                "label 3 at index 1 is not an integer in [0, 3)"
+            ))
+        );
+        assert!(f1_macro::<f64, _>(&y_true, &vec![0.5, 1.], 3).is_err());
+        assert!(recall_macro::<f64, _>(&y_true, &vec![-1., 1.], 3).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+//! # Distance
+//! Distance functions between two vectors, used by neighbor-based and clustering algorithms.
+
+use crate::linalg::BaseVector;
+use crate::numbers::Real;
+
+/// Distance between two vectors of equal length
+pub trait Distance<T: Real, V: BaseVector<T>> {
+    /// Compute the distance between `a` and `b`. Panics if their lengths differ.
+    fn distance(&self, a: &V, b: &V) -> T;
+}
+
+/// [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance), \\( \sqrt{\sum_i (a_i - b_i)^2} \\)
+#[derive(Clone, Debug, Default)]
+pub struct Euclidean;
+
+/// [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry), \\( \sum_i |a_i - b_i| \\)
+#[derive(Clone, Debug, Default)]
+pub struct Manhattan;
+
+/// [Minkowski distance](https://en.wikipedia.org/wiki/Minkowski_distance) of order `p`, \\( (\sum_i |a_i - b_i|^p)^{1/p} \\)
+#[derive(Clone, Debug)]
+pub struct Minkowski<T: Real> {
+    /// Order of the distance, `p = 1` is Manhattan and `p = 2` is Euclidean.
+    pub p: T,
+}
+
+fn check_lengths<T: Real, V: BaseVector<T>>(a: &V, b: &V) {
+    assert!(
+        a.len() == b.len(),
+        "distance: length mismatch (a={}, b={})",
+        a.len(),
+        b.len()
+    );
+}
+
+impl<T: Real, V: BaseVector<T>> Distance<T, V> for Euclidean {
+    fn distance(&self, a: &V, b: &V) -> T {
+        check_lengths(a, b);
+        a.sub(b).norm2()
+    }
+}
+
+impl<T: Real, V: BaseVector<T>> Distance<T, V> for Manhattan {
+    fn distance(&self, a: &V, b: &V) -> T {
+        check_lengths(a, b);
+        a.sub(b).l1_norm()
+    }
+}
+
+impl<T: Real, V: BaseVector<T>> Distance<T, V> for Minkowski<T> {
+    fn distance(&self, a: &V, b: &V) -> T {
+        check_lengths(a, b);
+        a.sub(b).norm(self.p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euclidean() {
+        let a = vec![0., 0.];
+        let b = vec![3., 4.];
+        assert_eq!(Euclidean.distance(&a, &b), 5.);
+    }
+
+    #[test]
+    fn manhattan() {
+        let a = vec![1., -2., 3.];
+        let b = vec![-1., 2., 3.];
+        assert_eq!(Manhattan.distance(&a, &b), 6.);
+    }
+
+    #[test]
+    fn minkowski() {
+        let a: Vec<f64> = vec![1., -2., 3.];
+        let b = vec![-1., 2., 0.5];
+        assert!((Minkowski { p: 2. }.distance(&a, &b) - Euclidean.distance(&a, &b)).abs() < 1e-12);
+        assert!((Minkowski { p: 1. }.distance(&a, &b) - Manhattan.distance(&a, &b)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "distance: length mismatch (a=2, b=3)")]
+    fn length_mismatch() {
+        Euclidean.distance(&vec![0., 0.], &vec![0., 0., 0.]);
+    }
+}
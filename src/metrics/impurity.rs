@@ -0,0 +1,87 @@
+//! # Impurity Measures
+//! Functions that score how mixed the class labels of a set of samples are, zero when all samples share one class.
+//! Labels are expected to be integers in `[0, n_classes)`.
+
+use crate::linalg::BaseVector;
+use crate::numbers::Real;
+
+fn class_counts<T: Real, V: BaseVector<T>>(labels: &V, n_classes: usize) -> Vec<usize> {
+    let mut counts = vec![0; n_classes];
+    for i in 0..labels.len() {
+        let label = labels.get(i);
+        match label.to_usize() {
+            Some(c) if c < n_classes && label.fract() == T::zero() => counts[c] += 1,
+            _ => panic!(
+                "labels must be integers in [0, {}), got {}",
+                n_classes, label
+            ),
+        }
+    }
+    counts
+}
+
+/// Gini impurity of class `counts` over `n` samples.
+pub(crate) fn gini_from_counts<T: Real>(counts: &[usize], n: usize) -> T {
+    if n == 0 {
+        return T::zero();
+    }
+    let n = T::from_usize(n).unwrap();
+    let mut impurity = T::one();
+    for &count in counts {
+        let p = T::from_usize(count).unwrap() / n;
+        impurity -= p * p;
+    }
+    impurity
+}
+
+/// Entropy, in bits, of class `counts` over `n` samples.
+pub(crate) fn entropy_from_counts<T: Real>(counts: &[usize], n: usize) -> T {
+    if n == 0 {
+        return T::zero();
+    }
+    let n = T::from_usize(n).unwrap();
+    let mut impurity = T::zero();
+    for &count in counts.iter().filter(|&&c| c > 0) {
+        let p = T::from_usize(count).unwrap() / n;
+        impurity -= p * p.log2();
+    }
+    impurity
+}
+
+/// [Gini impurity](https://en.wikipedia.org/wiki/Decision_tree_learning#Gini_impurity), \\( 1 - \sum_c p_c^2 \\).
+/// Returns zero for empty `labels`.
+pub fn gini_impurity<T: Real, V: BaseVector<T>>(labels: &V, n_classes: usize) -> T {
+    gini_from_counts(&class_counts(labels, n_classes), labels.len())
+}
+
+/// [Entropy](https://en.wikipedia.org/wiki/Entropy_(information_theory)) in bits, \\( -\sum_c p_c \log_2 p_c \\).
+/// Returns zero for empty `labels`.
+pub fn entropy<T: Real, V: BaseVector<T>>(labels: &V, n_classes: usize) -> T {
+    entropy_from_counts(&class_counts(labels, n_classes), labels.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pure_and_mixed() {
+        let pure = vec![1., 1., 1., 1.];
+        assert_eq!(gini_impurity::<f64, _>(&pure, 3), 0.);
+        assert_eq!(entropy::<f64, _>(&pure, 3), 0.);
+
+        let mixed = vec![0., 1., 1., 0.];
+        assert!((gini_impurity::<f64, _>(&mixed, 2) - 0.5).abs() < 1e-12);
+        assert!((entropy::<f64, _>(&mixed, 2) - 1.).abs() < 1e-12);
+
+        let empty: Vec<f64> = Vec::new();
+        assert_eq!(gini_impurity::<f64, _>(&empty, 2), 0.);
+        assert_eq!(entropy::<f64, _>(&empty, 2), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "labels must be integers in [0, 2), got 2")]
+    fn label_out_of_range() {
+        gini_impurity::<f64, _>(&vec![0., 2.], 2);
+    }
+}
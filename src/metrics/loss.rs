@@ -0,0 +1,97 @@
+//! # Loss Functions
+//! Differentiable losses between targets and predictions, with their gradients with respect to the predictions.
+
+use crate::linalg::BaseVector;
+use crate::metrics::check_lengths;
+use crate::numbers::Real;
+
+fn check_delta<T: Real>(delta: T) {
+    assert!(
+        delta > T::zero(),
+        "huber: delta must be positive, got {}",
+        delta
+    );
+}
+
+/// Mean [Huber loss](https://en.wikipedia.org/wiki/Huber_loss) of the residuals \\( r_i = y_i - \hat{y}_i \\):
+/// \\( r^2 \\) when \\( |r| \le \delta \\) and \\( 2\delta|r| - \delta^2 \\) otherwise, so small residuals are scored like
+/// [`mean_squared_error`](crate::metrics::regression::mean_squared_error). Panics if `delta` is not positive.
+pub fn huber_loss<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V, delta: T) -> T {
+    check_lengths(y_true, y_pred);
+    check_delta(delta);
+    let mut sum = T::zero();
+    for i in 0..y_true.len() {
+        let r = (y_true.get(i) - y_pred.get(i)).abs();
+        sum += if r <= delta {
+            r * r
+        } else {
+            T::two() * delta * r - delta * delta
+        };
+    }
+    sum / T::from_usize(y_true.len()).unwrap()
+}
+
+/// Gradient of [`huber_loss`] with respect to `y_pred`, \\( -2r_i / n \\) for small residuals and
+/// \\( -2\delta \operatorname{sign}(r_i) / n \\) beyond `delta`. Panics if `delta` is not positive.
+pub fn huber_gradient<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V, delta: T) -> V {
+    check_lengths(y_true, y_pred);
+    check_delta(delta);
+    let n = T::from_usize(y_true.len()).unwrap();
+    let mut grad = V::zeros(y_true.len());
+    for i in 0..y_true.len() {
+        let r = y_true.get(i) - y_pred.get(i);
+        let g = if r.abs() <= delta {
+            -r
+        } else {
+            -delta * Real::signum(r)
+        };
+        grad.set(i, T::two() * g / n);
+    }
+    grad
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_vector::DenseVector;
+    use crate::metrics::regression::{mean_absolute_error, mean_squared_error};
+
+    fn v(values: &[f64]) -> DenseVector<f64> {
+        DenseVector::new(values.to_vec())
+    }
+
+    #[test]
+    fn quadratic_then_linear() {
+        let y_true = v(&[1., 2., 3.]);
+
+        let close = v(&[1.1, 1.8, 3.3]);
+        let mse: f64 = mean_squared_error(&y_true, &close);
+        assert!((huber_loss(&y_true, &close, 1.) - mse).abs() < 1e-12);
+
+        let far = v(&[11., -8., 13.]);
+        let mae: f64 = mean_absolute_error(&y_true, &far);
+        assert!((huber_loss(&y_true, &far, 1.) - (2. * mae - 1.)).abs() < 1e-12);
+        let farther = v(&[21., -18., 23.]);
+        assert!(
+            (huber_loss(&y_true, &farther, 1.) - huber_loss(&y_true, &far, 1.) - 20.).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn gradient_is_continuous_at_delta() {
+        let y_true = v(&[0., 0.]);
+        let at = huber_gradient(&y_true, &v(&[2., -2.]), 2.);
+        let beyond = huber_gradient(&y_true, &v(&[2. + 1e-9, -2. - 1e-9]), 2.);
+        assert!(at.approximate_eq(&beyond, 1e-8));
+        assert_eq!(at, v(&[2., -2.]));
+
+        let small = huber_gradient(&y_true, &v(&[0.5, 0.]), 2.);
+        assert_eq!(small, v(&[0.5, 0.]));
+    }
+
+    #[test]
+    #[should_panic(expected = "huber: delta must be positive, got -1")]
+    fn negative_delta() {
+        huber_loss(&v(&[1.]), &v(&[1.]), -1.);
+    }
+}
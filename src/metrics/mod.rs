@@ -0,0 +1,22 @@
+//! # Metrics
+//! Functions to measure distances between samples, to evaluate the quality of predictions and the losses that
+//! estimators minimize.
+
+pub mod classification;
+pub mod distance;
+pub mod impurity;
+pub mod loss;
+pub mod regression;
+
+use crate::linalg::BaseVector;
+use crate::numbers::Real;
+
+/// Panics with `"length mismatch (y_true=.., y_pred=..)"` when the targets and predictions differ in length.
+pub(crate) fn check_lengths<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) {
+    assert!(
+        y_true.len() == y_pred.len(),
+        "length mismatch (y_true={}, y_pred={})",
+        y_true.len(),
+        y_pred.len()
+    );
+}
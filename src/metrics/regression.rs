@@ -0,0 +1,72 @@
+//! # Regression Metrics
+//! Functions that score continuous predictions against ground truth.
+
+use crate::linalg::BaseVector;
+use crate::metrics::check_lengths;
+use crate::numbers::Real;
+
+/// [Mean squared error](https://en.wikipedia.org/wiki/Mean_squared_error), \\( \frac{1}{n}\sum_i (y_i - \hat{y}_i)^2 \\)
+pub fn mean_squared_error<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> T {
+    check_lengths(y_true, y_pred);
+    let mut sum = T::zero();
+    for i in 0..y_true.len() {
+        sum += (y_true.get(i) - y_pred.get(i)).square();
+    }
+    sum / T::from_usize(y_true.len()).unwrap()
+}
+
+/// [Mean absolute error](https://en.wikipedia.org/wiki/Mean_absolute_error), \\( \frac{1}{n}\sum_i |y_i - \hat{y}_i| \\)
+pub fn mean_absolute_error<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> T {
+    check_lengths(y_true, y_pred);
+    let mut sum = T::zero();
+    for i in 0..y_true.len() {
+        sum += (y_true.get(i) - y_pred.get(i)).abs();
+    }
+    sum / T::from_usize(y_true.len()).unwrap()
+}
+
+/// [Coefficient of determination](https://en.wikipedia.org/wiki/Coefficient_of_determination), \\( R^2 = 1 - \frac{SS_{res}}{SS_{tot}} \\).
+/// Returns zero when `y_true` has zero variance.
+pub fn r2_score<T: Real, V: BaseVector<T>>(y_true: &V, y_pred: &V) -> T {
+    check_lengths(y_true, y_pred);
+    let mean = y_true.mean();
+    let mut ss_res = T::zero();
+    let mut ss_tot = T::zero();
+    for i in 0..y_true.len() {
+        ss_res += (y_true.get(i) - y_pred.get(i)).square();
+        ss_tot += (y_true.get(i) - mean).square();
+    }
+    if ss_tot == T::zero() {
+        T::zero()
+    } else {
+        T::one() - ss_res / ss_tot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perfect_predictions() {
+        let y: Vec<f64> = vec![1., 2., 3., 4.];
+        assert_eq!(mean_squared_error(&y, &y), 0.);
+        assert_eq!(mean_absolute_error(&y, &y), 0.);
+        assert_eq!(r2_score(&y, &y), 1.);
+    }
+
+    #[test]
+    fn imperfect_predictions() {
+        let y_true: Vec<f64> = vec![3., -0.5, 2., 7.];
+        let y_pred = vec![2.5, 0., 2., 8.];
+        assert_eq!(mean_squared_error(&y_true, &y_pred), 0.375);
+        assert_eq!(mean_absolute_error(&y_true, &y_pred), 0.5);
+        assert!((r2_score(&y_true, &y_pred) - 0.9486081370449679).abs() < 1e-12);
+    }
+
+    #[test]
+    fn r2_zero_variance() {
+        let y_true: Vec<f64> = vec![2., 2., 2.];
+        assert_eq!(r2_score(&y_true, &vec![1., 2., 3.]), 0.);
+    }
+}
@@ -0,0 +1,141 @@
+//! # Grid Search
+//! Exhaustive search over a list of parameter configurations, scoring each with k-fold cross-validation.
+
+use crate::base::BaseEstimator;
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::model_selection::cross_val_score;
+use crate::numbers::Real;
+
+/// Result of a cross-validated grid search
+#[derive(Clone, Debug)]
+pub struct GridSearchCV<T: Real, P> {
+    best_params: P,
+    best_score: T,
+    mean_scores: Vec<T>,
+}
+
+impl<T: Real, P: Clone> GridSearchCV<T, P> {
+    /// Run [`cross_val_score`] for every configuration in `param_grid` and keep the one with the highest mean score.
+    /// `estimator_factory` builds a fresh estimator from a configuration, which is also passed as its fit parameters.
+    /// Every configuration is evaluated on the same folds, drawn with `seed`. Ties go to the earlier configuration.
+    pub fn fit<M, V, E, F, S>(
+        param_grid: &[P],
+        estimator_factory: F,
+        x: &M,
+        y: &V,
+        k: usize,
+        scorer: S,
+        seed: u64,
+    ) -> Result<Self, Failure>
+    where
+        M: BaseMatrix<T>,
+        V: BaseVector<T>,
+        E: BaseEstimator<M, P, Failure>,
+        F: Fn(&P) -> E,
+        S: Fn(E, &M, &V) -> Result<T, Failure>,
+    {
+        if param_grid.is_empty() {
+            return Err(Failure::invalid_input("param_grid is empty"));
+        }
+
+        let mut mean_scores = Vec::with_capacity(param_grid.len());
+        let mut best = 0;
+        let mut best_mean = T::neg_infinity();
+        for (c, params) in param_grid.iter().enumerate() {
+            let scores = cross_val_score(
+                || estimator_factory(params),
+                params.clone(),
+                x,
+                y,
+                k,
+                &scorer,
+                seed,
+            )?;
+            let mean =
+                scores.iter().fold(T::zero(), |s, &v| s + v) / T::from_usize(scores.len()).unwrap();
+            if mean > best_mean {
+                best = c;
+                best_mean = mean;
+            }
+            mean_scores.push(mean);
+        }
+
+        Ok(GridSearchCV {
+            best_params: param_grid[best].clone(),
+            best_score: mean_scores[best],
+            mean_scores,
+        })
+    }
+
+    /// Configuration with the highest mean score.
+    pub fn best_params(&self) -> &P {
+        &self.best_params
+    }
+
+    /// Mean cross-validation score of the best configuration.
+    pub fn best_score(&self) -> T {
+        self.best_score
+    }
+
+    /// Mean cross-validation score of every configuration, in the order of `param_grid`.
+    pub fn mean_scores(&self) -> &[T] {
+        &self.mean_scores
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::Regressor;
+    use crate::error::FailedError;
+    use crate::linear::ridge::{RidgeRegression, RidgeRegressionParameters};
+    use crate::metrics::regression::r2_score;
+
+    fn r2(
+        model: RidgeRegression<f64, Vec<Vec<f64>>>,
+        x: &Vec<Vec<f64>>,
+        y: &Vec<f64>,
+    ) -> Result<f64, Failure> {
+        let y_hat: Vec<f64> = model.predict(x)?.get_col(0);
+        Ok(r2_score(y, &y_hat))
+    }
+
+    #[test]
+    fn ridge_alpha() {
+        let x: Vec<Vec<f64>> = (0..30)
+            .map(|i| vec![i as f64, ((i * 7) % 11) as f64])
+            .collect();
+        let y: Vec<f64> = x
+            .iter()
+            .enumerate()
+            .map(|(i, r)| 3. * r[0] - 2. * r[1] + 5. + 0.1 * ((i % 3) as f64 - 1.))
+            .collect();
+
+        let grid: Vec<RidgeRegressionParameters<f64>> = [1e4, 1e-2]
+            .iter()
+            .map(|&alpha| RidgeRegressionParameters {
+                alpha,
+                ..Default::default()
+            })
+            .collect();
+        let search =
+            GridSearchCV::fit(&grid, |_| RidgeRegression::default(), &x, &y, 5, r2, 42).unwrap();
+
+        assert_eq!(search.mean_scores().len(), 2);
+        assert!(search.mean_scores()[1] > search.mean_scores()[0]);
+        assert_eq!(search.best_params().alpha, 1e-2);
+        assert_eq!(search.best_score(), search.mean_scores()[1]);
+        assert!(search.best_score() > 0.99);
+    }
+
+    #[test]
+    fn empty_grid() {
+        let x: Vec<Vec<f64>> = BaseMatrix::ones(4, 1);
+        let y: Vec<f64> = vec![1., 2., 3., 4.];
+        let grid: Vec<RidgeRegressionParameters<f64>> = Vec::new();
+        let err =
+            GridSearchCV::fit(&grid, |_| RidgeRegression::default(), &x, &y, 2, r2, 0).unwrap_err();
+        assert_eq!(err.error(), FailedError::InvalidInput);
+    }
+}
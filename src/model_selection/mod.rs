@@ -0,0 +1,177 @@
+//! # Model Selection
+//! Utilities for splitting data and evaluating estimators on held-out samples.
+
+pub mod grid_search;
+pub mod split;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::base::BaseEstimator;
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::model_selection::split::take_rows;
+use crate::numbers::Real;
+
+/// Evaluate an estimator with [k-fold cross-validation](https://en.wikipedia.org/wiki/Cross-validation_(statistics)#k-fold_cross-validation).
+/// Rows are shuffled with a generator seeded by `seed` and partitioned into `k` folds. For every fold a fresh estimator
+/// from `estimator_factory` is fitted on the remaining `k - 1` folds with `fit_params`, then `scorer` receives the fitted
+/// estimator and the held-out rows. Returns one score per fold.
+pub fn cross_val_score<T, M, V, E, P, F, S>(
+    estimator_factory: F,
+    fit_params: P,
+    x: &M,
+    y: &V,
+    k: usize,
+    scorer: S,
+    seed: u64,
+) -> Result<Vec<T>, Failure>
+where
+    T: Real,
+    M: BaseMatrix<T>,
+    V: BaseVector<T>,
+    E: BaseEstimator<M, P, Failure>,
+    P: Clone,
+    F: Fn() -> E,
+    S: Fn(E, &M, &V) -> Result<T, Failure>,
+{
+    let (nrows, _) = x.shape();
+    if nrows != y.len() {
+        return Err(Failure::invalid_input(&format!(
+            "x has {} samples, y has {}",
+            nrows,
+            y.len()
+        )));
+    }
+    if k < 2 || k > nrows {
+        return Err(Failure::invalid_input(&format!(
+            "k must be in [2, {}], got {}",
+            nrows, k
+        )));
+    }
+
+    let mut indices: Vec<usize> = (0..nrows).collect();
+    indices.shuffle(&mut StdRng::seed_from_u64(seed));
+
+    let mut scores = Vec::with_capacity(k);
+    for fold in 0..k {
+        let (start, end) = (fold * nrows / k, (fold + 1) * nrows / k);
+        let train: Vec<usize> = indices[..start]
+            .iter()
+            .chain(indices[end..].iter())
+            .copied()
+            .collect();
+        let (x_train, y_train) = take_rows(x, y, &train);
+        let (x_test, y_test) = take_rows(x, y, &indices[start..end]);
+
+        let mut y_train_col = M::zeros(y_train.len(), 1);
+        y_train_col.set_col(0, &y_train);
+        let estimator = estimator_factory().fit(&x_train, &y_train_col, fit_params.clone())?;
+        scores.push(scorer(estimator, &x_test, &y_test)?);
+    }
+    Ok(scores)
+}
+
+/// Permute the rows of `x` and the elements of `y` in place with one permutation drawn from a generator seeded by
+/// `seed`, so every row keeps its label. Panics if `x` and `y` have a different number of samples.
+pub fn shuffle_rows_with_labels<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    x: &mut M,
+    y: &mut V,
+    seed: u64,
+) {
+    let (nrows, _) = x.shape();
+    if nrows != y.len() {
+        panic!(
+            "shuffle_rows_with_labels: length mismatch (x={}, y={})",
+            nrows,
+            y.len()
+        );
+    }
+    if nrows < 2 {
+        return;
+    }
+
+    let mut indices: Vec<usize> = (0..nrows).collect();
+    indices.shuffle(&mut StdRng::seed_from_u64(seed));
+    let (x_shuffled, y_shuffled) = take_rows(x, y, &indices);
+    *x = x_shuffled;
+    *y = y_shuffled;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::Regressor;
+    use crate::error::FailedError;
+    use crate::linear::linear_regression::LinearRegression;
+    use crate::metrics::regression::r2_score;
+
+    fn r2(
+        model: LinearRegression<f64, Vec<Vec<f64>>, Vec<f64>>,
+        x: &Vec<Vec<f64>>,
+        y: &Vec<f64>,
+    ) -> Result<f64, Failure> {
+        let y_hat: Vec<f64> = model.predict(x)?.get_col(0);
+        Ok(r2_score(y, &y_hat))
+    }
+
+    #[test]
+    fn linear_regression_folds() {
+        let x: Vec<Vec<f64>> = (0..20)
+            .map(|i| vec![i as f64, ((i * 7) % 11) as f64])
+            .collect();
+        let y: Vec<f64> = x.iter().map(|r| 3. * r[0] - 2. * r[1] + 5.).collect();
+
+        let scores = cross_val_score(
+            LinearRegression::default,
+            Default::default(),
+            &x,
+            &y,
+            4,
+            r2,
+            42,
+        )
+        .unwrap();
+
+        assert_eq!(scores.len(), 4);
+        assert!(scores.iter().all(|s| (s - 1.).abs() < 1e-8));
+    }
+
+    #[test]
+    fn too_many_folds() {
+        let x: Vec<Vec<f64>> = BaseMatrix::ones(3, 1);
+        let y: Vec<f64> = vec![1., 2., 3.];
+        let err = cross_val_score(
+            LinearRegression::default,
+            Default::default(),
+            &x,
+            &y,
+            4,
+            r2,
+            0,
+        )
+        .unwrap_err();
+        assert_eq!(err.error(), FailedError::InvalidInput);
+    }
+
+    #[test]
+    fn shuffle_keeps_pairs() {
+        let mut x: Vec<Vec<f64>> = (0..10).map(|i| vec![i as f64, (i * i) as f64]).collect();
+        let mut y: Vec<f64> = (0..10).map(|i| 100. + i as f64).collect();
+        let (x0, y0) = (x.clone(), y.clone());
+
+        shuffle_rows_with_labels(&mut x, &mut y, 7);
+
+        assert_ne!(x, x0);
+        let mut pairs: Vec<(Vec<f64>, f64)> = x.into_iter().zip(y).collect();
+        pairs.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        let expected: Vec<(Vec<f64>, f64)> = x0.into_iter().zip(y0).collect();
+        assert_eq!(pairs, expected);
+
+        let mut x: Vec<Vec<f64>> = vec![vec![1., 2.]];
+        let mut y: Vec<f64> = vec![3.];
+        shuffle_rows_with_labels(&mut x, &mut y, 7);
+        assert_eq!((x, y), (vec![vec![1., 2.]], vec![3.]));
+    }
+}
@@ -0,0 +1,204 @@
+//! # Train/Test Split
+//! Randomly partitions samples into a training and a test set.
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// Shuffle the rows of `x` and `y` with a generator seeded by `seed` and split them into `(x_train, y_train, x_test,
+/// y_test)`, where the test set holds `ceil(test_ratio * n)` samples. Panics if `test_ratio` is not in `(0, 1)` or if
+/// `x` and `y` have a different number of samples.
+pub fn train_test_split<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    x: &M,
+    y: &V,
+    test_ratio: T,
+    seed: u64,
+) -> (M, V, M, V) {
+    if !(test_ratio > T::zero() && test_ratio < T::one()) {
+        panic!(
+            "train_test_split: test_ratio must be in (0, 1), got {}",
+            test_ratio
+        );
+    }
+    let (nrows, _) = x.shape();
+    if nrows != y.len() {
+        panic!(
+            "train_test_split: length mismatch (x={}, y={})",
+            nrows,
+            y.len()
+        );
+    }
+
+    let mut indices: Vec<usize> = (0..nrows).collect();
+    indices.shuffle(&mut StdRng::seed_from_u64(seed));
+    let n_test = (test_ratio * T::from_usize(nrows).unwrap())
+        .ceil()
+        .to_usize()
+        .unwrap();
+    let (test, train) = indices.split_at(n_test);
+
+    let (x_train, y_train) = take_rows(x, y, train);
+    let (x_test, y_test) = take_rows(x, y, test);
+    (x_train, y_train, x_test, y_test)
+}
+
+/// Split like [`train_test_split`], but shuffle and split the rows of every class separately so that the test set holds
+/// about `test_ratio` of each class. `y` must hold integer-valued labels and every class needs at least two samples,
+/// otherwise `FailedError::InvalidInput` is returned. Panics if `test_ratio` is not in `(0, 1)` or if `x` and `y` have
+/// a different number of samples.
+pub fn stratified_split<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    x: &M,
+    y: &V,
+    test_ratio: T,
+    seed: u64,
+) -> Result<(M, V, M, V), Failure> {
+    if !(test_ratio > T::zero() && test_ratio < T::one()) {
+        panic!(
+            "stratified_split: test_ratio must be in (0, 1), got {}",
+            test_ratio
+        );
+    }
+    let (nrows, _) = x.shape();
+    if nrows != y.len() {
+        panic!(
+            "stratified_split: length mismatch (x={}, y={})",
+            nrows,
+            y.len()
+        );
+    }
+
+    let mut labels = y.unique();
+    if let Some(label) = labels.iter().find(|l| l.fract() != T::zero()) {
+        return Err(Failure::invalid_input(&format!(
+            "labels must be integer-valued, got {}",
+            label
+        )));
+    }
+    labels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut train = Vec::new();
+    let mut test = Vec::new();
+    for &label in labels.iter() {
+        let mut group: Vec<usize> = (0..nrows).filter(|&i| y.get(i) == label).collect();
+        if group.len() < 2 {
+            return Err(Failure::invalid_input(&format!(
+                "class {} has {} sample(s), at least 2 are needed to split",
+                label,
+                group.len()
+            )));
+        }
+        group.shuffle(&mut rng);
+        let n_test = (test_ratio * T::from_usize(group.len()).unwrap())
+            .round()
+            .to_usize()
+            .unwrap()
+            .clamp(1, group.len() - 1);
+        test.extend_from_slice(&group[..n_test]);
+        train.extend_from_slice(&group[n_test..]);
+    }
+    train.shuffle(&mut rng);
+    test.shuffle(&mut rng);
+
+    let (x_train, y_train) = take_rows(x, y, &train);
+    let (x_test, y_test) = take_rows(x, y, &test);
+    Ok((x_train, y_train, x_test, y_test))
+}
+
+/// Rows of `x` and elements of `y` at `indices`, in that order.
+pub(crate) fn take_rows<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>(
+    x: &M,
+    y: &V,
+    indices: &[usize],
+) -> (M, V) {
+    let (_, ncols) = x.shape();
+    let mut x_sel = M::zeros(indices.len(), ncols);
+    let mut y_sel = V::zeros(indices.len());
+    for (k, &i) in indices.iter().enumerate() {
+        for j in 0..ncols {
+            x_sel.set(k, j, x.get(i, j));
+        }
+        y_sel.set(k, y.get(i));
+    }
+    (x_sel, y_sel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_covers_all_rows() {
+        let x: Vec<Vec<f64>> = (0..10).map(|i| vec![i as f64, 10. * i as f64]).collect();
+        let y: Vec<f64> = (0..10).map(|i| i as f64).collect();
+
+        let (x_train, y_train, x_test, y_test) = train_test_split(&x, &y, 0.25, 7);
+        assert_eq!(x_train.shape(), (7, 2));
+        assert_eq!(x_test.shape(), (3, 2));
+
+        for (xs, ys) in [(&x_train, &y_train), (&x_test, &y_test)].iter() {
+            for (row, label) in xs.iter().zip(ys.iter()) {
+                assert_eq!(row[0], *label);
+                assert_eq!(row[1], 10. * label);
+            }
+        }
+
+        let mut seen: Vec<f64> = y_train.iter().chain(y_test.iter()).copied().collect();
+        seen.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(seen, y);
+
+        let (_, y_train2, _, _) = train_test_split(&x, &y, 0.25, 7);
+        assert_eq!(y_train, y_train2);
+    }
+
+    #[test]
+    #[should_panic(expected = "train_test_split: test_ratio must be in (0, 1), got 1")]
+    fn invalid_ratio() {
+        let x: Vec<Vec<f64>> = BaseMatrix::zeros(4, 2);
+        let y: Vec<f64> = vec![0.; 4];
+        train_test_split(&x, &y, 1., 0);
+    }
+
+    #[test]
+    fn stratified_preserves_class_ratios() {
+        let y: Vec<f64> = (0..68)
+            .map(|i| match i {
+                0..=39 => 0.,
+                40..=59 => 1.,
+                _ => 2.,
+            })
+            .collect();
+        let x: Vec<Vec<f64>> = y
+            .iter()
+            .enumerate()
+            .map(|(i, &l)| vec![i as f64, l])
+            .collect();
+
+        let (x_train, y_train, x_test, y_test) = stratified_split(&x, &y, 0.25, 3).unwrap();
+        for (&label, &(n_train, n_test)) in
+            [0., 1., 2.].iter().zip([(30, 10), (15, 5), (6, 2)].iter())
+        {
+            assert_eq!(y_train.iter().filter(|&&l| l == label).count(), n_train);
+            assert_eq!(y_test.iter().filter(|&&l| l == label).count(), n_test);
+        }
+        for (xs, ys) in [(&x_train, &y_train), (&x_test, &y_test)].iter() {
+            for (row, label) in xs.iter().zip(ys.iter()) {
+                assert_eq!(row[1], *label);
+            }
+        }
+    }
+
+    #[test]
+    fn stratified_too_few_samples() {
+        let x: Vec<Vec<f64>> = BaseMatrix::zeros(5, 1);
+        let y: Vec<f64> = vec![0., 0., 0., 0., 1.];
+        assert_eq!(
+            stratified_split(&x, &y, 0.5, 0).unwrap_err(),
+            Failure::invalid_input("class 1 has 1 sample(s), at least 2 are needed to split")
+        );
+    }
+}
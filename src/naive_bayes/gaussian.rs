@@ -0,0 +1,258 @@
+//! # Gaussian Naive Bayes
+//! Models every feature as normally distributed within each class and predicts the class with the largest
+//! log-posterior \\( \ln P(c) + \sum_j \ln \mathcal{N}(x_j; \mu_{cj}, \sigma^2_{cj}) \\).
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Classifier, ProbabilisticClassifier};
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::{logsumexp, Real};
+
+/// Gaussian naive Bayes parameters
+#[derive(Clone, Debug)]
+pub struct GaussianNBParameters<T: Real> {
+    /// Fraction of the largest feature variance added to every variance, keeps constant features finite. The added
+    /// amount is never below `T::epsilon()`, so an all-constant design matrix still has positive variances.
+    pub var_smoothing: T,
+}
+
+impl<T: Real> Default for GaussianNBParameters<T> {
+    fn default() -> Self {
+        GaussianNBParameters {
+            var_smoothing: T::from_f64(1e-9).unwrap(),
+        }
+    }
+}
+
+/// Gaussian naive Bayes classifier
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GaussianNB<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> {
+    classes: Vec<T>,
+    log_priors: Vec<T>,
+    means: M,
+    vars: M,
+    _phantom: PhantomData<V>,
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Default for GaussianNB<T, M, V> {
+    fn default() -> Self {
+        GaussianNB {
+            classes: Vec::new(),
+            log_priors: Vec::new(),
+            means: M::zeros(0, 0),
+            vars: M::zeros(0, 0),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> GaussianNB<T, M, V> {
+    /// Class labels in order of first appearance in the training targets, the row order of
+    /// [`means`](GaussianNB::means) and the column order of `predict_proba`.
+    pub fn classes(&self) -> &[T] {
+        &self.classes
+    }
+
+    /// Per-class feature means, one row per class.
+    pub fn means(&self) -> &M {
+        &self.means
+    }
+
+    /// Per-class feature variances including smoothing, one row per class.
+    pub fn vars(&self) -> &M {
+        &self.vars
+    }
+
+    /// Log-posterior of every class for a single sample, normalized with `logsumexp`.
+    fn log_posterior(&self, x: &V) -> V {
+        let (n_classes, n_features) = self.means.shape();
+        let ln_2pi = T::from_f64(2. * std::f64::consts::PI).unwrap().ln();
+        let mut jll = V::zeros(n_classes);
+        for c in 0..n_classes {
+            let mut ll = self.log_priors[c];
+            for j in 0..n_features {
+                let var = self.vars.get(c, j);
+                ll -= T::half()
+                    * ((var.ln() + ln_2pi) + (x.get(j) - self.means.get(c, j)).square() / var);
+            }
+            jll.set(c, ll);
+        }
+        let norm = logsumexp(&jll);
+        jll.sub_scalar(norm)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> BaseEstimator<M, GaussianNBParameters<T>, Failure>
+    for GaussianNB<T, M, V>
+{
+    fn fit(self, x: &M, y: &M, fit_params: GaussianNBParameters<T>) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if fit_params.var_smoothing < T::zero() {
+            return Err(Failure::fit(&format!(
+                "var_smoothing must be non-negative, got {}",
+                fit_params.var_smoothing
+            )));
+        }
+
+        let mut max_var = T::zero();
+        for j in 0..ncols {
+            max_var = max_var.max(x.get_col::<V>(j).var());
+        }
+        let epsilon = (fit_params.var_smoothing * max_var).max(T::epsilon());
+
+        let classes = y.get_col::<V>(0).unique();
+        let mut log_priors = Vec::with_capacity(classes.len());
+        let mut means = M::zeros(classes.len(), ncols);
+        let mut vars = M::zeros(classes.len(), ncols);
+        for (c, &label) in classes.iter().enumerate() {
+            let rows: Vec<usize> = (0..nrows).filter(|&i| y.get(i, 0) == label).collect();
+            log_priors
+                .push((T::from_usize(rows.len()).unwrap() / T::from_usize(nrows).unwrap()).ln());
+            for j in 0..ncols {
+                let values: Vec<T> = rows.iter().map(|&i| x.get(i, j)).collect();
+                let col = V::from_array(&values);
+                means.set(c, j, col.mean());
+                vars.set(c, j, col.var() + epsilon);
+            }
+        }
+
+        Ok(GaussianNB {
+            classes,
+            log_priors,
+            means,
+            vars,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> GaussianNB<T, M, V> {
+    fn check_features(&self, x: &M) -> Result<(), Failure> {
+        let (_, ncols) = x.shape();
+        let (_, n_features) = self.means.shape();
+        if ncols != n_features {
+            return Err(Failure::predict(&format!(
+                "x has {} features, model was fitted with {}",
+                ncols, n_features
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Classifier<M, Failure> for GaussianNB<T, M, V> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        self.check_features(x)?;
+        let (nrows, _) = x.shape();
+        let mut labels = M::zeros(nrows, 1);
+        for i in 0..nrows {
+            let c = self.log_posterior(&x.get_row(i)).argmax();
+            labels.set(i, 0, self.classes[c]);
+        }
+        Ok(labels)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> ProbabilisticClassifier<M, Failure>
+    for GaussianNB<T, M, V>
+{
+    /// One column per class, ordered as [`classes`](GaussianNB::classes).
+    fn predict_proba(&self, x: &M) -> Result<M, Failure> {
+        self.check_features(x)?;
+        let (nrows, _) = x.shape();
+        let mut proba = M::zeros(nrows, self.classes.len());
+        for i in 0..nrows {
+            let posterior = self.log_posterior(&x.get_row(i));
+            for c in 0..self.classes.len() {
+                proba.set(i, c, posterior.get(c).exp());
+            }
+        }
+        Ok(proba)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separable() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[
+            &[1., 2., 0.],
+            &[1.5, 1.8, 0.],
+            &[0.8, 2.3, 0.],
+            &[1.2, 2.1, 0.],
+            &[6., 8., 0.],
+            &[6.5, 7.6, 0.],
+            &[5.8, 8.4, 0.],
+            &[6.2, 8.1, 0.],
+        ]);
+        let y: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[0.], &[0.], &[0.], &[0.], &[1.], &[1.], &[1.], &[1.]]);
+
+        let nb: GaussianNB<f64, _, Vec<f64>> = GaussianNB::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        assert!(nb.vars().iter().flatten().all(|v| v.is_finite() && *v > 0.));
+
+        let posterior = nb.log_posterior(&vec![1., 2., 0.]);
+        assert!((posterior.iter().map(|p| p.exp()).sum::<f64>() - 1.).abs() < 1e-12);
+
+        assert_eq!(nb.predict(&x).unwrap(), y);
+    }
+
+    #[test]
+    fn constant_features() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.], &[1.], &[1.], &[1.]]);
+        let y: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[0.], &[0.], &[1.], &[1.]]);
+
+        let nb: GaussianNB<f64, _, Vec<f64>> = GaussianNB::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        assert!(nb.vars().iter().flatten().all(|v| *v > 0.));
+
+        let posterior = nb.log_posterior(&vec![1.]);
+        assert!(posterior.iter().all(|p| p.is_finite()));
+        assert!((posterior.iter().map(|p| p.exp()).sum::<f64>() - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn predict_proba_matches_predict() {
+        use crate::linalg::dense_matrix::DenseMatrix;
+        use crate::linalg::dense_vector::DenseVector;
+
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 2.],
+            &[2., 1.],
+            &[5., 5.],
+            &[6., 5.],
+            &[1., 6.],
+            &[2., 7.],
+            &[3.5, 4.],
+        ]);
+        let y = DenseMatrix::from_2d_array(&[&[2.], &[2.], &[0.], &[0.], &[1.], &[1.], &[0.]]);
+        let nb: GaussianNB<f64, _, DenseVector<f64>> = GaussianNB::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        assert_eq!(nb.classes(), &[2., 0., 1.]);
+
+        let proba = nb.predict_proba(&x).unwrap();
+        let labels = nb.predict(&x).unwrap();
+        assert_eq!(proba.shape(), (7, 3));
+        for i in 0..7 {
+            let row: DenseVector<f64> = proba.get_row(i);
+            assert!((row.sum() - 1.).abs() < 1e-12);
+            assert_eq!(nb.classes()[row.argmax()], labels.get(i, 0));
+        }
+    }
+}
@@ -0,0 +1,4 @@
+//! # Naive Bayes
+//! Probabilistic classifiers that apply Bayes' theorem assuming features are conditionally independent given the class.
+
+pub mod gaussian;
@@ -0,0 +1,230 @@
+//! # K-Nearest Neighbors Classifier
+//! Predicts the majority label among the `k` training samples closest to each query row.
+//!
+//! ```
+//! # #[cfg(feature = "datasets")]
+//! # fn main() {
+//! use cora::base::{BaseEstimator, Classifier};
+//! use cora::dataset::load_iris;
+//! use cora::linalg::dense_matrix::DenseMatrix;
+//! use cora::linalg::dense_vector::DenseVector;
+//! use cora::linalg::BaseMatrix;
+//! use cora::metrics::classification::accuracy;
+//! use cora::neighbors::knn_classifier::KNNClassifier;
+//!
+//! let (x, y): (DenseMatrix<f64>, DenseVector<f64>) = load_iris();
+//! let mut labels = DenseMatrix::zeros(150, 1);
+//! labels.set_col(0, &y);
+//!
+//! let knn: KNNClassifier<f64, _, DenseVector<f64>> = KNNClassifier::default()
+//!     .fit(&x, &labels, Default::default())
+//!     .unwrap();
+//! let y_hat: DenseVector<f64> = knn.predict(&x).unwrap().get_col(0);
+//! assert!(accuracy(&y, &y_hat) > 0.9);
+//! # }
+//! # #[cfg(not(feature = "datasets"))]
+//! # fn main() {}
+//! ```
+
+use crate::base::{BaseEstimator, Classifier};
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::metrics::distance::{Distance, Euclidean};
+use crate::numbers::Real;
+
+/// KNN classifier parameters
+pub struct KNNClassifierParameters<T: Real, V: BaseVector<T>> {
+    /// Number of neighbors that vote on the label.
+    pub k: usize,
+    /// Distance used to rank training samples.
+    pub distance: Box<dyn Distance<T, V>>,
+}
+
+impl<T: Real, V: BaseVector<T>> Default for KNNClassifierParameters<T, V> {
+    fn default() -> Self {
+        KNNClassifierParameters {
+            k: 3,
+            distance: Box::new(Euclidean),
+        }
+    }
+}
+
+/// K-nearest neighbors classifier
+pub struct KNNClassifier<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> {
+    x: M,
+    y: M,
+    k: usize,
+    distance: Box<dyn Distance<T, V>>,
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Default for KNNClassifier<T, M, V> {
+    fn default() -> Self {
+        KNNClassifier {
+            x: M::zeros(0, 0),
+            y: M::zeros(0, 1),
+            k: 0,
+            distance: Box::new(Euclidean),
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>>
+    BaseEstimator<M, KNNClassifierParameters<T, V>, Failure> for KNNClassifier<T, M, V>
+{
+    fn fit(self, x: &M, y: &M, fit_params: KNNClassifierParameters<T, V>) -> Result<Self, Failure> {
+        let (nrows, _) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if fit_params.k == 0 {
+            return Err(Failure::fit("k must be at least 1"));
+        }
+
+        Ok(KNNClassifier {
+            x: x.clone(),
+            y: y.clone(),
+            k: fit_params.k,
+            distance: fit_params.distance,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Classifier<M, Failure>
+    for KNNClassifier<T, M, V>
+{
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        let (n_train, n_features) = self.x.shape();
+        let (nrows, ncols) = x.shape();
+        if self.k > n_train {
+            return Err(Failure::predict(&format!(
+                "k = {} exceeds the number of training samples {}",
+                self.k, n_train
+            )));
+        }
+        if ncols != n_features {
+            return Err(Failure::predict(&format!(
+                "x has {} features, model was fitted with {}",
+                ncols, n_features
+            )));
+        }
+
+        let train_rows: Vec<V> = (0..n_train).map(|i| self.x.get_row(i)).collect();
+        let mut labels = M::zeros(nrows, 1);
+        for i in 0..nrows {
+            let query: V = x.get_row(i);
+            let distances: Vec<T> = train_rows
+                .iter()
+                .map(|row| self.distance.distance(&query, row))
+                .collect();
+            let neighbors = V::from_array(&distances).argsort(true);
+
+            // (label, votes), in order of the nearest neighbor carrying each label
+            let mut votes: Vec<(T, usize)> = Vec::new();
+            for &j in neighbors.iter().take(self.k) {
+                let label = self.y.get(j, 0);
+                match votes.iter_mut().find(|(l, _)| *l == label) {
+                    Some((_, count)) => *count += 1,
+                    None => votes.push((label, 1)),
+                }
+            }
+
+            let mut best = 0;
+            for c in 1..votes.len() {
+                if votes[c].1 > votes[best].1 {
+                    best = c;
+                }
+            }
+            labels.set(i, 0, votes[best].0);
+        }
+        Ok(labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::distance::Manhattan;
+
+    fn data() -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let x = BaseMatrix::from_2d_array(&[
+            &[1., 1.],
+            &[1., 2.],
+            &[2., 1.],
+            &[6., 6.],
+            &[6., 7.],
+            &[7., 6.],
+        ]);
+        let y = BaseMatrix::from_2d_array(&[&[0.], &[0.], &[0.], &[1.], &[1.], &[1.]]);
+        (x, y)
+    }
+
+    #[test]
+    fn fit_predict() {
+        let (x, y) = data();
+        let knn: KNNClassifier<f64, Vec<Vec<f64>>, Vec<f64>> = KNNClassifier::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let query: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[1.5, 1.5], &[6.5, 6.5]]);
+        assert_eq!(knn.predict(&query).unwrap(), vec![vec![0.], vec![1.]]);
+    }
+
+    #[test]
+    fn tie_prefers_nearest() {
+        let (x, y) = data();
+        let knn: KNNClassifier<f64, Vec<Vec<f64>>, Vec<f64>> = KNNClassifier::default()
+            .fit(
+                &x,
+                &y,
+                KNNClassifierParameters {
+                    k: 2,
+                    distance: Box::new(Manhattan),
+                },
+            )
+            .unwrap();
+
+        let query: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[&[4., 4.]]);
+        assert_eq!(knn.predict(&query).unwrap(), vec![vec![1.]]);
+    }
+
+    #[test]
+    fn k_exceeds_samples() {
+        let (x, y) = data();
+        let knn: KNNClassifier<f64, Vec<Vec<f64>>, Vec<f64>> = KNNClassifier::default()
+            .fit(
+                &x,
+                &y,
+                KNNClassifierParameters {
+                    k: 7,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            knn.predict(&x).unwrap_err(),
+            Failure::predict("k = 7 exceeds the number of training samples 6")
+        );
+    }
+
+    #[test]
+    fn predict_iter_matches_predict() {
+        let (x, y) = data();
+        let knn: KNNClassifier<f64, _, Vec<f64>> = KNNClassifier::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let streamed: Vec<Vec<f64>> = knn
+            .predict_iter(&x)
+            .collect::<Result<Vec<Vec<f64>>, Failure>>()
+            .unwrap();
+        assert_eq!(streamed, knn.predict(&x).unwrap());
+
+        let boxed: &dyn Classifier<Vec<Vec<f64>>, Failure> = &knn;
+        assert_eq!(boxed.predict(&x).unwrap(), streamed);
+    }
+}
@@ -0,0 +1,4 @@
+//! # Nearest Neighbors
+//! Estimators that predict from the training samples closest to each query point.
+
+pub mod knn_classifier;
@@ -9,6 +9,8 @@ use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
 use num_traits::{Float, FromPrimitive};
 use rand::Rng;
 
+use crate::linalg::BaseVector;
+
 /// Defines real number
 /// <script type="text/javascript" src="https://cdnjs.cloudflare.com/ajax/libs/mathjax/2.7.0/MathJax.js?config=TeX-AMS_CHTML"></script>
 pub trait Real:
@@ -17,6 +19,8 @@ pub trait Real:
     + Debug
     + Display
     + Copy
+    + Send
+    + Sync
     + Sum
     + Product
     + AddAssign
@@ -34,7 +38,12 @@ pub trait Real:
     fn sigmoid(self) -> Self;
 
     /// Return psudorandom number between 0 and 1
-    fn rand() -> Self;
+    fn rand() -> Self {
+        Self::rand_with(&mut rand::thread_rng())
+    }
+
+    /// Return psudorandom number between 0 and 1 drawn from `rng`, pass a seeded rng for reproducible results
+    fn rand_with<R: Rng>(rng: &mut R) -> Self;
 
     /// Return 2
     fn two() -> Self;
@@ -47,8 +56,68 @@ pub trait Real:
         self * self
     }
 
+    /// Return \\( x^n \\) computed by exponentiation by squaring. Negative exponents give the reciprocal and
+    /// `n == 0` gives one, including for zero and NaN.
+    fn powi(self, n: i32) -> Self {
+        let mut base = self;
+        let mut exp = n.unsigned_abs();
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        if n < 0 {
+            Self::one() / result
+        } else {
+            result
+        }
+    }
+
     /// Raw transmutation to u64
     fn to_f32_bits(self) -> u32;
+
+    /// Raw transmutation to u64. For `f32` the 32 bits of the value are zero-extended.
+    fn to_bits(self) -> u64;
+
+    /// Raw transmutation from u64. For `f32` only the lower 32 bits are used, so
+    /// `from_bits(to_bits(x))` round-trips exactly for both float types.
+    fn from_bits(bits: u64) -> Self;
+
+    /// Return `-1`, `0` or `1` depending on the sign of the number.
+    /// Unlike `f64::signum`, both `0.0` and `-0.0` map to `0`.
+    fn signum(self) -> Self {
+        if self == Self::zero() {
+            Self::zero()
+        } else {
+            Real::copysign(Self::one(), self)
+        }
+    }
+
+    /// Return `1` or `-1` with the sign of the number, `-0.0` maps to `-1`.
+    fn sign_nonzero(self) -> Self {
+        Real::copysign(Self::one(), self)
+    }
+
+    /// Restrict the number to `[min, max]`. NaN is returned unchanged.
+    /// Panics if `min > max`.
+    fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min <= max,
+            "clamp: min must not be greater than max (min={}, max={})",
+            min,
+            max
+        );
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
 }
 
 impl Real for f64 {
@@ -76,8 +145,7 @@ impl Real for f64 {
         }
     }
 
-    fn rand() -> Self {
-        let mut rng = rand::thread_rng();
+    fn rand_with<R: Rng>(rng: &mut R) -> Self {
         rng.gen()
     }
 
@@ -90,7 +158,15 @@ impl Real for f64 {
     }
 
     fn to_f32_bits(self) -> u32 {
-        self.to_bits() as u32
+        f64::to_bits(self) as u32
+    }
+
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
     }
 }
 
@@ -119,8 +195,7 @@ impl Real for f32 {
         }
     }
 
-    fn rand() -> Self {
-        let mut rng = rand::thread_rng();
+    fn rand_with<R: Rng>(rng: &mut R) -> Self {
         rng.gen()
     }
 
@@ -133,7 +208,48 @@ impl Real for f32 {
     }
 
     fn to_f32_bits(self) -> u32 {
-        self.to_bits() as u32
+        f32::to_bits(self)
+    }
+
+    fn to_bits(self) -> u64 {
+        u64::from(f32::to_bits(self))
+    }
+
+    fn from_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+/// Compute \\( \ln \sum_i e^{v_i} \\) without overflow by shifting all elements by the maximum.
+/// Returns negative infinity for an empty vector.
+pub fn logsumexp<T: Real, V: BaseVector<T>>(v: &V) -> T {
+    if v.is_empty() {
+        return T::neg_infinity();
+    }
+    let max = BaseVector::max(v);
+    if max.is_infinite() {
+        return max;
+    }
+    let mut sum = T::zero();
+    for i in 0..v.len() {
+        sum += (v.get(i) - max).exp();
+    }
+    max + sum.ln()
+}
+
+/// Compute the [softmax](https://en.wikipedia.org/wiki/Softmax_function) \\( \frac{e^{v_i}}{\sum_j e^{v_j}} \\),
+/// a probability vector that sums to one.
+pub fn softmax<T: Real, V: BaseVector<T>>(v: &V) -> V {
+    let mut r = v.clone();
+    softmax_mut(&mut r);
+    r
+}
+
+/// Replace every element of `v` with its softmax probability.
+pub fn softmax_mut<T: Real, V: BaseVector<T>>(v: &mut V) {
+    let lse = logsumexp(v);
+    for i in 0..v.len() {
+        v.set(i, (v.get(i) - lse).exp());
     }
 }
 
@@ -147,4 +263,100 @@ mod tests {
         assert_eq!(41.0.sigmoid(), 1.);
         assert_eq!((-41.0).sigmoid(), 0.);
     }
+
+    #[test]
+    fn signum() {
+        assert_eq!(Real::signum(2.5f64), 1.);
+        assert_eq!(Real::signum(-0.1f64), -1.);
+        assert_eq!(Real::signum(0f64), 0.);
+        assert_eq!(Real::signum(-0f64), 0.);
+        assert_eq!(Real::signum(-3f32), -1.);
+
+        assert_eq!(2.5f64.sign_nonzero(), 1.);
+        assert_eq!((-0.1f64).sign_nonzero(), -1.);
+        assert_eq!(0f64.sign_nonzero(), 1.);
+        assert_eq!((-0f64).sign_nonzero(), -1.);
+    }
+
+    #[test]
+    fn powi() {
+        assert_eq!(Real::powi(2f64, 10), 1024.);
+        assert_eq!(Real::powi(2f64, -2), 0.25);
+        assert_eq!(Real::powi(-3f32, 3), -27.);
+        assert_eq!(Real::powi(1.5f64, 1), 1.5);
+        for &x in &[0f64, -2.5, f64::NAN, f64::INFINITY] {
+            assert_eq!(Real::powi(x, 0), 1.);
+        }
+        assert_eq!(Real::powi(0f64, -1), f64::INFINITY);
+        assert!((Real::powi(1.1f64, 7) - 1.1f64.powf(7.)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn clamp() {
+        assert_eq!(Real::clamp(-2f64, 0., 1.), 0.);
+        assert_eq!(Real::clamp(0.5f64, 0., 1.), 0.5);
+        assert_eq!(Real::clamp(3f64, 0., 1.), 1.);
+        assert_eq!(Real::clamp(0f64, 0., 1.), 0.);
+        assert_eq!(Real::clamp(1f64, 0., 1.), 1.);
+        assert_eq!(Real::clamp(2f32, 1., 1.), 1.);
+        assert!(Real::clamp(f64::NAN, 0., 1.).is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp: min must not be greater than max (min=1, max=0)")]
+    fn clamp_out_of_order() {
+        Real::clamp(0.5f64, 1., 0.);
+    }
+
+    #[test]
+    fn logsumexp() {
+        let v: Vec<f64> = vec![1., 2., 3., -0.5];
+        let naive = v.iter().map(|x| x.exp()).sum::<f64>().ln();
+        assert!((super::logsumexp(&v) - naive).abs() < 1e-12);
+
+        assert_eq!(super::logsumexp(&vec![4.2f64]), 4.2);
+        assert_eq!(super::logsumexp(&Vec::<f64>::new()), f64::NEG_INFINITY);
+        assert!((super::logsumexp(&vec![1000f64, 1000.]) - (1000. + 2f64.ln())).abs() < 1e-12);
+    }
+
+    #[test]
+    fn softmax() {
+        let p = super::softmax(&vec![1000f64, 1001., 1002.]);
+        assert!((p.sum() - 1.).abs() < 1e-12);
+        assert!(p[0] < p[1] && p[1] < p[2]);
+        assert!(p.approximate_eq(&vec![0.09003057, 0.24472847, 0.66524096], 1e-8));
+
+        let mut q: Vec<f64> = vec![0., 0.];
+        softmax_mut(&mut q);
+        assert_eq!(q, vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn rand_with() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        let a: Vec<f64> = (0..5).map(|_| f64::rand_with(&mut rng1)).collect();
+        let b: Vec<f64> = (0..5).map(|_| f64::rand_with(&mut rng2)).collect();
+        assert_eq!(a, b);
+        assert!(a.iter().all(|&x| (0. ..1.).contains(&x)));
+
+        let x = f32::rand();
+        assert!((0. ..1.).contains(&x));
+    }
+
+    #[test]
+    fn bits() {
+        for &x in &[0.1f64, -0.0, 1e300, f64::INFINITY] {
+            assert_eq!(<f64 as Real>::from_bits(Real::to_bits(x)), x);
+            assert_eq!(Real::to_bits(x), x.to_bits());
+        }
+        for &x in &[0.1f32, -0.0, 3.4e38] {
+            assert_eq!(<f32 as Real>::from_bits(Real::to_bits(x)), x);
+            assert_eq!(Real::to_bits(x), u64::from(x.to_bits()));
+        }
+        assert!(<f64 as Real>::from_bits(Real::to_bits(f64::NAN)).is_nan());
+    }
 }
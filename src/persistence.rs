@@ -0,0 +1,457 @@
+//! # Persistence
+//! Save fitted estimators to JSON files and load them back.
+//!
+//! Floats are written with the shortest representation that parses back to the same value, so finite parameters
+//! round-trip exactly. JSON has no NaN or infinity, so `save` fails on a model holding such values instead of writing
+//! a file that can not be loaded.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+
+use serde::de::DeserializeOwned;
+use serde::ser::{self, Serialize};
+
+use crate::cluster::kmeans::KMeans;
+use crate::decomposition::pca::PCA;
+use crate::ensemble::random_forest::RandomForestClassifier;
+use crate::error::Failure;
+use crate::feature_selection::variance_threshold::VarianceThreshold;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::linear::elastic_net::ElasticNet;
+use crate::linear::lasso::Lasso;
+use crate::linear::linear_regression::LinearRegression;
+use crate::linear::logistic::LogisticRegression;
+use crate::linear::perceptron::Perceptron;
+use crate::linear::ridge::RidgeRegression;
+use crate::naive_bayes::gaussian::GaussianNB;
+use crate::numbers::Real;
+use crate::preprocessing::minmax_scaler::MinMaxScaler;
+use crate::preprocessing::one_hot::OneHotEncoder;
+use crate::preprocessing::polynomial::PolynomialFeatures;
+use crate::preprocessing::standard_scaler::StandardScaler;
+use crate::tree::decision_tree::DecisionTreeClassifier;
+
+/// JSON persistence of fitted estimators.
+pub trait Persist: Serialize + DeserializeOwned {
+    /// Write `self` as JSON to `path`, replacing the file if it exists. Fails with `FailedError::InvalidInput`, without
+    /// touching `path`, if `self` holds a NaN or infinite value.
+    fn save(&self, path: &str) -> Result<(), Failure> {
+        self.serialize(&mut FiniteCheck)
+            .map_err(|e| Failure::invalid_input(&e.0))?;
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Read a value previously written with [`save`](Persist::save).
+    fn load(path: &str) -> Result<Self, Failure> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Persist for KMeans<T, M, V> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Persist for PCA<T, M, V> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for RandomForestClassifier<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for VarianceThreshold<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for ElasticNet<T, M> where Self: Serialize + DeserializeOwned
+{}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for Lasso<T, M> where Self: Serialize + DeserializeOwned {}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Persist for LinearRegression<T, M, V> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for LogisticRegression<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for Perceptron<T, M> where Self: Serialize + DeserializeOwned
+{}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for RidgeRegression<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Persist for GaussianNB<T, M, V> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for MinMaxScaler<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Persist for OneHotEncoder<T, M, V> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for PolynomialFeatures<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for StandardScaler<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+impl<T: Real, M: BaseMatrix<T>> Persist for DecisionTreeClassifier<T, M> where
+    Self: Serialize + DeserializeOwned
+{
+}
+
+/// Serializer that writes nothing and fails on the first non-finite float.
+struct FiniteCheck;
+
+#[derive(Debug)]
+struct NonFinite(String);
+
+impl fmt::Display for NonFinite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for NonFinite {}
+
+impl ser::Error for NonFinite {
+    fn custom<M: fmt::Display>(msg: M) -> Self {
+        NonFinite(msg.to_string())
+    }
+}
+
+impl FiniteCheck {
+    fn check(v: f64) -> Result<(), NonFinite> {
+        if v.is_finite() {
+            Ok(())
+        } else {
+            Err(NonFinite(format!("can not save non-finite value {}", v)))
+        }
+    }
+}
+
+impl ser::Serializer for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, _: bool) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_i8(self, _: i8) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_i16(self, _: i16) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_i32(self, _: i32) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_i64(self, _: i64) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_u8(self, _: u8) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_u16(self, _: u16) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_u32(self, _: u32) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_u64(self, _: u64) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_f32(self, v: f32) -> Result<(), NonFinite> {
+        FiniteCheck::check(f64::from(v))
+    }
+    fn serialize_f64(self, v: f64) -> Result<(), NonFinite> {
+        FiniteCheck::check(v)
+    }
+    fn serialize_char(self, _: char) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_str(self, _: &str) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_none(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_some<S: ?Sized + Serialize>(self, value: &S) -> Result<(), NonFinite> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), NonFinite> {
+        Ok(())
+    }
+    fn serialize_newtype_struct<S: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &S,
+    ) -> Result<(), NonFinite> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<S: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &S,
+    ) -> Result<(), NonFinite> {
+        value.serialize(self)
+    }
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+    fn serialize_tuple(self, _: usize) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, NonFinite> {
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    fn serialize_element<S: ?Sized + Serialize>(&mut self, value: &S) -> Result<(), NonFinite> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    fn serialize_element<S: ?Sized + Serialize>(&mut self, value: &S) -> Result<(), NonFinite> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    fn serialize_field<S: ?Sized + Serialize>(&mut self, value: &S) -> Result<(), NonFinite> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    fn serialize_field<S: ?Sized + Serialize>(&mut self, value: &S) -> Result<(), NonFinite> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    fn serialize_key<S: ?Sized + Serialize>(&mut self, key: &S) -> Result<(), NonFinite> {
+        key.serialize(&mut **self)
+    }
+    fn serialize_value<S: ?Sized + Serialize>(&mut self, value: &S) -> Result<(), NonFinite> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    fn serialize_field<S: ?Sized + Serialize>(
+        &mut self,
+        _: &'static str,
+        value: &S,
+    ) -> Result<(), NonFinite> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut FiniteCheck {
+    type Ok = ();
+    type Error = NonFinite;
+    fn serialize_field<S: ?Sized + Serialize>(
+        &mut self,
+        _: &'static str,
+        value: &S,
+    ) -> Result<(), NonFinite> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), NonFinite> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{BaseEstimator, Classifier, Regressor};
+    use crate::error::FailedError;
+    use crate::linalg::dense_matrix::DenseMatrix;
+    use crate::linalg::dense_vector::DenseVector;
+    use crate::linalg::BaseMatrix;
+    use crate::linear::linear_regression::LinearRegression;
+    use crate::linear::logistic::LogisticRegression;
+
+    fn tmp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("cora-{}-{}.json", name, std::process::id()))
+            .to_str()
+            .unwrap()
+            .to_owned()
+    }
+
+    #[test]
+    fn round_trip_linear_regression() {
+        let x = DenseMatrix::from_2d_array(&[
+            &[0.1, 1.],
+            &[1.3, 0.],
+            &[2., 3.7],
+            &[3., 1.1],
+            &[4.2, 2.],
+        ]);
+        let y = DenseMatrix::from_2d_array(&[&[3.3], &[2.1], &[12.7], &[9.], &[14.4]]);
+        let lr: LinearRegression<f64, _, DenseVector<f64>> = LinearRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let path = tmp_path("linear");
+        lr.save(&path).unwrap();
+        let loaded: LinearRegression<f64, DenseMatrix<f64>, DenseVector<f64>> =
+            Persist::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.coefficients(), lr.coefficients());
+        assert_eq!(loaded.intercept().to_bits(), lr.intercept().to_bits());
+        assert_eq!(loaded.predict(&x).unwrap(), lr.predict(&x).unwrap());
+    }
+
+    #[test]
+    fn round_trip_logistic_regression() {
+        let x = DenseMatrix::from_2d_array(&[&[1., 1.], &[2., 0.5], &[4., 4.], &[5., 3.5]]);
+        let y = DenseMatrix::from_2d_array(&[&[0.], &[0.], &[1.], &[1.]]);
+        let lr = LogisticRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let path = tmp_path("logistic");
+        lr.save(&path).unwrap();
+        let loaded = LogisticRegression::<f64, DenseMatrix<f64>>::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.coefficients(), lr.coefficients());
+        assert_eq!(loaded.predict(&x).unwrap(), lr.predict(&x).unwrap());
+    }
+
+    #[test]
+    fn load_failures() {
+        let missing =
+            LinearRegression::<f64, DenseMatrix<f64>, DenseVector<f64>>::load(&tmp_path("missing"));
+        assert_eq!(missing.unwrap_err().error(), FailedError::IoFailed);
+
+        let path = tmp_path("garbage");
+        std::fs::write(&path, "{\"coefficients\": 1}").unwrap();
+        let garbage = LinearRegression::<f64, DenseMatrix<f64>, DenseVector<f64>>::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(garbage.unwrap_err().error(), FailedError::InvalidInput);
+    }
+
+    #[test]
+    fn save_rejects_non_finite() {
+        let x = DenseMatrix::from_2d_array(&[&[0.], &[1.], &[2.]]);
+        let y = DenseMatrix::from_2d_array(&[&[1.], &[f64::NAN], &[5.]]);
+        let lr: LinearRegression<f64, _, DenseVector<f64>> = LinearRegression::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let path = tmp_path("non-finite");
+        let err = lr.save(&path).unwrap_err();
+        assert_eq!(err.error(), FailedError::InvalidInput);
+        assert!(!std::path::Path::new(&path).exists());
+    }
+}
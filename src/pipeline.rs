@@ -0,0 +1,164 @@
+//! # Pipeline
+//! Chains transformers with a final estimator, so preprocessing is fitted on the training data and applied
+//! identically at prediction time.
+//!
+//! Transformers are stateful: during [`fit`](BaseEstimator::fit) every step is fitted, in order, on the output of the
+//! previous step, and must store everything its `transform` needs. Steps are fitted only once, fitting an already
+//! fitted pipeline again reuses the fitted transformers and refits only the final estimator.
+
+use std::marker::PhantomData;
+
+use crate::base::{BaseEstimator, Classifier, Regressor, Transformer};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+type FitStep<M> = Box<dyn FnOnce(&M, &M) -> Result<Box<dyn Transformer<M, Failure>>, Failure>>;
+
+/// Ordered list of transformers followed by an estimator
+pub struct Pipeline<T: Real, M: BaseMatrix<T>, E> {
+    unfitted: Vec<FitStep<M>>,
+    transformers: Vec<Box<dyn Transformer<M, Failure>>>,
+    estimator: E,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: Real, M: BaseMatrix<T> + 'static, E> Pipeline<T, M, E> {
+    /// Create a pipeline that ends with the unfitted `estimator`.
+    pub fn new(estimator: E) -> Self {
+        Pipeline {
+            unfitted: Vec::new(),
+            transformers: Vec::new(),
+            estimator,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Append an unfitted transformer, fitted with `fit_params` when the pipeline is fitted.
+    pub fn with_step<S, P>(mut self, transformer: S, fit_params: P) -> Self
+    where
+        S: BaseEstimator<M, P, Failure> + Transformer<M, Failure> + 'static,
+        P: 'static,
+    {
+        self.unfitted.push(Box::new(move |x: &M, y: &M| {
+            let fitted: Box<dyn Transformer<M, Failure>> =
+                Box::new(transformer.fit(x, y, fit_params)?);
+            Ok(fitted)
+        }));
+        self
+    }
+
+    /// Final estimator.
+    pub fn estimator(&self) -> &E {
+        &self.estimator
+    }
+
+    fn transform_all(&self, x: &M) -> Result<M, Failure> {
+        let mut data = x.clone();
+        for transformer in self.transformers.iter() {
+            data = transformer.transform(&data)?;
+        }
+        Ok(data)
+    }
+}
+
+impl<T, M, E, P> BaseEstimator<M, P, Failure> for Pipeline<T, M, E>
+where
+    T: Real,
+    M: BaseMatrix<T> + 'static,
+    E: BaseEstimator<M, P, Failure>,
+{
+    /// Fit every step on the output of the previous one, then fit the estimator with `fit_params`.
+    fn fit(self, x: &M, y: &M, fit_params: P) -> Result<Self, Failure> {
+        let Pipeline {
+            unfitted,
+            mut transformers,
+            estimator,
+            ..
+        } = self;
+
+        let mut data = x.clone();
+        for transformer in transformers.iter() {
+            data = transformer.transform(&data)?;
+        }
+        for step in unfitted {
+            let transformer = step(&data, y)?;
+            data = transformer.transform(&data)?;
+            transformers.push(transformer);
+        }
+        let estimator = estimator.fit(&data, y, fit_params)?;
+
+        Ok(Pipeline {
+            unfitted: Vec::new(),
+            transformers,
+            estimator,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T, M, E> Classifier<M, Failure> for Pipeline<T, M, E>
+where
+    T: Real,
+    M: BaseMatrix<T> + 'static,
+    E: Classifier<M, Failure>,
+{
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        self.estimator.predict(&self.transform_all(x)?)
+    }
+}
+
+impl<T, M, E> Regressor<M, Failure> for Pipeline<T, M, E>
+where
+    T: Real,
+    M: BaseMatrix<T> + 'static,
+    E: Regressor<M, Failure>,
+{
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        self.estimator.predict(&self.transform_all(x)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+    use crate::linear::logistic::LogisticRegression;
+    use crate::preprocessing::standard_scaler::StandardScaler;
+
+    #[test]
+    fn scaler_then_logistic() {
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 100.],
+            &[1.5, 200.],
+            &[2., 100.],
+            &[2., 50.],
+            &[4., 400.],
+            &[5., 350.],
+            &[4.5, 500.],
+            &[3.5, 450.],
+        ]);
+        let y =
+            DenseMatrix::from_2d_array(&[&[0.], &[0.], &[0.], &[0.], &[1.], &[1.], &[1.], &[1.]]);
+        let x_new = DenseMatrix::from_2d_array(&[&[1.2, 120.], &[4.2, 420.], &[3., 260.]]);
+
+        let pipeline = Pipeline::new(LogisticRegression::default())
+            .with_step(StandardScaler::default(), Default::default())
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let scaler = StandardScaler::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        let lr = LogisticRegression::default()
+            .fit(&scaler.transform(&x).unwrap(), &y, Default::default())
+            .unwrap();
+
+        assert_eq!(pipeline.estimator().coefficients(), lr.coefficients());
+        assert_eq!(
+            pipeline.predict(&x_new).unwrap(),
+            lr.predict(&scaler.transform(&x_new).unwrap()).unwrap()
+        );
+        assert_eq!(pipeline.predict(&x).unwrap(), y);
+    }
+}
@@ -0,0 +1,170 @@
+//! # Min-Max Scaler
+//! Linearly maps each column from its observed `[min, max]` onto a target `feature_range`.
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Transformer};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Min-max scaler parameters
+#[derive(Clone, Debug)]
+pub struct MinMaxScalerParameters<T: Real> {
+    /// Target `(min, max)` range of the transformed columns.
+    pub feature_range: (T, T),
+}
+
+impl<T: Real> Default for MinMaxScalerParameters<T> {
+    fn default() -> Self {
+        MinMaxScalerParameters {
+            feature_range: (T::zero(), T::one()),
+        }
+    }
+}
+
+/// Per-column min-max scaling. Constant columns are mapped to the low end of the range.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MinMaxScaler<T: Real, M: BaseMatrix<T>> {
+    data_min: Vec<T>,
+    data_range: Vec<T>,
+    feature_range: (T, T),
+    _phantom: PhantomData<M>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for MinMaxScaler<T, M> {
+    fn default() -> Self {
+        MinMaxScaler {
+            data_min: Vec::new(),
+            data_range: Vec::new(),
+            feature_range: (T::zero(), T::one()),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> MinMaxScaler<T, M> {
+    /// Per-column minimum seen during `fit`.
+    pub fn data_min(&self) -> &[T] {
+        &self.data_min
+    }
+
+    /// Per-column `max - min` seen during `fit`.
+    pub fn data_range(&self) -> &[T] {
+        &self.data_range
+    }
+
+    /// Map scaled data back to the original feature space.
+    pub fn inverse_transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = self.check_shape(x)?;
+        let (lo, hi) = self.feature_range;
+        let mut r = M::zeros(nrows, ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                let unit = (x.get(i, j) - lo) / (hi - lo);
+                r.set(i, j, unit * self.data_range[j] + self.data_min[j]);
+            }
+        }
+        Ok(r)
+    }
+
+    fn check_shape(&self, x: &M) -> Result<(usize, usize), Failure> {
+        let (nrows, ncols) = x.shape();
+        if ncols != self.data_min.len() {
+            return Err(Failure::transform(&format!(
+                "x has {} features, scaler was fitted with {}",
+                ncols,
+                self.data_min.len()
+            )));
+        }
+        Ok((nrows, ncols))
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, MinMaxScalerParameters<T>, Failure>
+    for MinMaxScaler<T, M>
+{
+    /// Learn per-column minimum and maximum of `x`, `y` is ignored.
+    fn fit(self, x: &M, _y: &M, fit_params: MinMaxScalerParameters<T>) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        if nrows == 0 {
+            return Err(Failure::fit("x must have at least one row"));
+        }
+        let (lo, hi) = fit_params.feature_range;
+        if lo >= hi {
+            return Err(Failure::fit(&format!(
+                "feature_range min must be smaller than max, got ({}, {})",
+                lo, hi
+            )));
+        }
+
+        let mut data_min = vec![T::infinity(); ncols];
+        let mut data_max = vec![T::neg_infinity(); ncols];
+        for i in 0..nrows {
+            for j in 0..ncols {
+                data_min[j] = data_min[j].min(x.get(i, j));
+                data_max[j] = data_max[j].max(x.get(i, j));
+            }
+        }
+        let data_range = (0..ncols).map(|j| data_max[j] - data_min[j]).collect();
+
+        Ok(MinMaxScaler {
+            data_min,
+            data_range,
+            feature_range: fit_params.feature_range,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Transformer<M, Failure> for MinMaxScaler<T, M> {
+    fn transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = self.check_shape(x)?;
+        let (lo, hi) = self.feature_range;
+        let mut r = M::zeros(nrows, ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                let unit = if self.data_range[j] == T::zero() {
+                    T::zero()
+                } else {
+                    (x.get(i, j) - self.data_min[j]) / self.data_range[j]
+                };
+                r.set(i, j, lo + unit * (hi - lo));
+            }
+        }
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::BaseVector;
+
+    #[test]
+    fn transform_and_inverse() {
+        let x: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., -10., 5.], &[2., 20., 5.], &[5., 60., 5.]]);
+        let scaler = MinMaxScaler::default()
+            .fit(
+                &x,
+                &x,
+                MinMaxScalerParameters {
+                    feature_range: (-1., 1.),
+                },
+            )
+            .unwrap();
+        let z = scaler.transform(&x).unwrap();
+
+        assert!(z[0].approximate_eq(&vec![-1., -1., -1.], 1e-12));
+        assert!(z[1].approximate_eq(&vec![-0.5, -1. / 7., -1.], 1e-12));
+        assert!(z[2].approximate_eq(&vec![1., 1., -1.], 1e-12));
+
+        let x_back = scaler.inverse_transform(&z).unwrap();
+        for i in 0..3 {
+            assert!(x_back[i].approximate_eq(&x[i], 1e-10));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! # Preprocessing
+//! Transformers that rescale or re-encode features before they are passed to an estimator.
+
+pub mod minmax_scaler;
+pub mod one_hot;
+pub mod polynomial;
+pub mod standard_scaler;
@@ -0,0 +1,201 @@
+//! # One-Hot Encoder
+//! Replaces integer-valued categorical columns with one indicator column per category.
+//!
+//! Every encoded column is expanded in place, its indicator columns follow the ascending order of the categories seen
+//! during `fit`. Columns that are not encoded are passed through unchanged.
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Transformer};
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::numbers::Real;
+
+/// What to do with a category that was not seen during `fit`
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum HandleUnknown {
+    /// Fail the transform with `FailedError::TransformFailed`.
+    Error,
+    /// Encode the value as all zeros.
+    Ignore,
+}
+
+/// One-hot encoder parameters
+#[derive(Clone, Debug)]
+pub struct OneHotEncoderParameters {
+    /// Indices of the categorical columns to encode.
+    pub columns: Vec<usize>,
+    /// Behavior for categories not seen during `fit`.
+    pub handle_unknown: HandleUnknown,
+}
+
+impl Default for OneHotEncoderParameters {
+    fn default() -> Self {
+        OneHotEncoderParameters {
+            columns: Vec::new(),
+            handle_unknown: HandleUnknown::Error,
+        }
+    }
+}
+
+/// One-hot encoder for categorical integer columns
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OneHotEncoder<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> {
+    /// Sorted categories of every input column, `None` for columns that are passed through.
+    categories: Vec<Option<Vec<T>>>,
+    handle_unknown: HandleUnknown,
+    _phantom: PhantomData<(M, V)>,
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Default for OneHotEncoder<T, M, V> {
+    fn default() -> Self {
+        OneHotEncoder {
+            categories: Vec::new(),
+            handle_unknown: HandleUnknown::Error,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> OneHotEncoder<T, M, V> {
+    /// Sorted categories learned for input column `j`, `None` if the column is not encoded.
+    pub fn categories(&self, j: usize) -> Option<&[T]> {
+        self.categories.get(j).and_then(|c| c.as_deref())
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> BaseEstimator<M, OneHotEncoderParameters, Failure>
+    for OneHotEncoder<T, M, V>
+{
+    /// Learn the categories of the selected columns, `y` is ignored.
+    fn fit(self, x: &M, _y: &M, fit_params: OneHotEncoderParameters) -> Result<Self, Failure> {
+        let (_, ncols) = x.shape();
+        let mut categories: Vec<Option<Vec<T>>> = vec![None; ncols];
+        for &j in fit_params.columns.iter() {
+            if j >= ncols {
+                return Err(Failure::fit(&format!(
+                    "column {} out of bounds for {} columns",
+                    j, ncols
+                )));
+            }
+            let mut values = x.get_col::<V>(j).unique();
+            if let Some(v) = values.iter().find(|v| v.fract() != T::zero()) {
+                return Err(Failure::invalid_input(&format!(
+                    "column {} has non-integer category {}",
+                    j, v
+                )));
+            }
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            categories[j] = Some(values);
+        }
+
+        Ok(OneHotEncoder {
+            categories,
+            handle_unknown: fit_params.handle_unknown,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>, V: BaseVector<T>> Transformer<M, Failure>
+    for OneHotEncoder<T, M, V>
+{
+    fn transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = x.shape();
+        if ncols != self.categories.len() {
+            return Err(Failure::transform(&format!(
+                "x has {} features, encoder was fitted with {}",
+                ncols,
+                self.categories.len()
+            )));
+        }
+        let width = self
+            .categories
+            .iter()
+            .map(|c| c.as_ref().map_or(1, |c| c.len()))
+            .sum();
+
+        let mut r = M::zeros(nrows, width);
+        for i in 0..nrows {
+            let mut offset = 0;
+            for j in 0..ncols {
+                let value = x.get(i, j);
+                match &self.categories[j] {
+                    None => {
+                        r.set(i, offset, value);
+                        offset += 1;
+                    }
+                    Some(categories) => {
+                        match categories.iter().position(|&c| c == value) {
+                            Some(k) => r.set(i, offset + k, T::one()),
+                            None if self.handle_unknown == HandleUnknown::Ignore => {}
+                            None => {
+                                return Err(Failure::transform(&format!(
+                                    "unknown category {} in column {}",
+                                    value, j
+                                )))
+                            }
+                        }
+                        offset += categories.len();
+                    }
+                }
+            }
+        }
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+    use crate::linalg::dense_vector::DenseVector;
+
+    type Encoder = OneHotEncoder<f64, DenseMatrix<f64>, DenseVector<f64>>;
+
+    fn fit(handle_unknown: HandleUnknown) -> Encoder {
+        let x = DenseMatrix::from_2d_array(&[&[0.5, 2.], &[1.5, 0.], &[2.5, 1.], &[3.5, 2.]]);
+        Encoder::default()
+            .fit(
+                &x,
+                &x,
+                OneHotEncoderParameters {
+                    columns: vec![1],
+                    handle_unknown,
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn three_categories() {
+        let encoder = fit(HandleUnknown::Error);
+        assert_eq!(encoder.categories(1), Some(&[0., 1., 2.][..]));
+        assert_eq!(encoder.categories(0), None);
+
+        let x = DenseMatrix::from_2d_array(&[&[0.5, 2.], &[1.5, 0.], &[2.5, 1.]]);
+        assert_eq!(
+            encoder.transform(&x).unwrap(),
+            DenseMatrix::from_2d_array(&[
+                &[0.5, 0., 0., 1.],
+                &[1.5, 1., 0., 0.],
+                &[2.5, 0., 1., 0.]
+            ])
+        );
+    }
+
+    #[test]
+    fn unknown_categories() {
+        let x = DenseMatrix::from_2d_array(&[&[9., 7.]]);
+
+        let err = fit(HandleUnknown::Error).transform(&x).unwrap_err();
+        assert_eq!(err, Failure::transform("unknown category 7 in column 1"));
+
+        assert_eq!(
+            fit(HandleUnknown::Ignore).transform(&x).unwrap(),
+            DenseMatrix::from_2d_array(&[&[9., 0., 0., 0.]])
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! # Polynomial Features
+//! Expands every row into all monomials of its features up to a given degree.
+//!
+//! Output columns are ordered by degree, and within a degree by the lexicographic order of the non-decreasing feature
+//! index tuples. For features `[a, b]` and degree 2 with a bias column the layout is `[1, a, b, a², ab, b²]`.
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Transformer};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Polynomial features parameters
+#[derive(Clone, Debug)]
+pub struct PolynomialFeaturesParameters {
+    /// Maximum degree of the generated monomials, must be at least 1.
+    pub degree: usize,
+    /// Prepend a column of ones, the degree-0 term.
+    pub include_bias: bool,
+    /// Reject the expansion if it would produce more columns than this.
+    pub max_output_features: usize,
+}
+
+impl Default for PolynomialFeaturesParameters {
+    fn default() -> Self {
+        PolynomialFeaturesParameters {
+            degree: 2,
+            include_bias: true,
+            max_output_features: 10_000,
+        }
+    }
+}
+
+/// Polynomial feature expansion
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PolynomialFeatures<T: Real, M: BaseMatrix<T>> {
+    n_features: usize,
+    /// Feature indices multiplied together for every output column, empty for the bias.
+    powers: Vec<Vec<usize>>,
+    _phantom: PhantomData<(T, M)>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for PolynomialFeatures<T, M> {
+    fn default() -> Self {
+        PolynomialFeatures {
+            n_features: 0,
+            powers: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> PolynomialFeatures<T, M> {
+    /// Number of columns produced by `transform`.
+    pub fn n_output_features(&self) -> usize {
+        self.powers.len()
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, PolynomialFeaturesParameters, Failure>
+    for PolynomialFeatures<T, M>
+{
+    /// Record the number of features of `x` and enumerate the output monomials, `y` is ignored.
+    fn fit(self, x: &M, _y: &M, fit_params: PolynomialFeaturesParameters) -> Result<Self, Failure> {
+        let (_, n_features) = x.shape();
+        if fit_params.degree == 0 {
+            return Err(Failure::fit("degree must be at least 1"));
+        }
+
+        let too_many = || {
+            Failure::invalid_input(&format!(
+                "expanding {} features to degree {} exceeds max_output_features = {}",
+                n_features, fit_params.degree, fit_params.max_output_features
+            ))
+        };
+
+        let mut powers: Vec<Vec<usize>> = Vec::new();
+        if fit_params.include_bias {
+            powers.push(Vec::new());
+        }
+        let mut previous: Vec<Vec<usize>> = vec![Vec::new()];
+        for _ in 0..fit_params.degree {
+            let mut current = Vec::new();
+            for combination in previous.iter() {
+                let start = combination.last().copied().unwrap_or(0);
+                for j in start..n_features {
+                    if powers.len() + current.len() >= fit_params.max_output_features {
+                        return Err(too_many());
+                    }
+                    let mut next = combination.clone();
+                    next.push(j);
+                    current.push(next);
+                }
+            }
+            powers.extend(current.iter().cloned());
+            previous = current;
+        }
+
+        Ok(PolynomialFeatures {
+            n_features,
+            powers,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Transformer<M, Failure> for PolynomialFeatures<T, M> {
+    fn transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = x.shape();
+        if ncols != self.n_features {
+            return Err(Failure::transform(&format!(
+                "x has {} features, transformer was fitted with {}",
+                ncols, self.n_features
+            )));
+        }
+        let mut r = M::zeros(nrows, self.powers.len());
+        for i in 0..nrows {
+            for (c, combination) in self.powers.iter().enumerate() {
+                let value = combination
+                    .iter()
+                    .fold(T::one(), |acc, &j| acc * x.get(i, j));
+                r.set(i, c, value);
+            }
+        }
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::FailedError;
+    use crate::linalg::dense_matrix::DenseMatrix;
+
+    #[test]
+    fn degree_two_layout() {
+        let x = DenseMatrix::from_2d_array(&[&[2., 3.], &[-1., 4.]]);
+        let z = PolynomialFeatures::default()
+            .fit_transform(&x, &x, Default::default())
+            .unwrap();
+
+        assert_eq!(
+            z,
+            DenseMatrix::from_2d_array(&[&[1., 2., 3., 4., 6., 9.], &[1., -1., 4., 1., -4., 16.]])
+        );
+    }
+
+    #[test]
+    fn without_bias() {
+        let x = DenseMatrix::from_2d_array(&[&[2., 3., 5.]]);
+        let poly = PolynomialFeatures::default()
+            .fit(
+                &x,
+                &x,
+                PolynomialFeaturesParameters {
+                    degree: 3,
+                    include_bias: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(poly.n_output_features(), 3 + 6 + 10);
+        assert_eq!(poly.transform(&x).unwrap().get(0, 18), 125.);
+    }
+
+    #[test]
+    fn max_output_features() {
+        let x: DenseMatrix<f64> = DenseMatrix::zeros(1, 10);
+        let err = PolynomialFeatures::default()
+            .fit(
+                &x,
+                &x,
+                PolynomialFeaturesParameters {
+                    degree: 3,
+                    max_output_features: 100,
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err.error(), FailedError::InvalidInput);
+    }
+}
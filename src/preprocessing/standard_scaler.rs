@@ -0,0 +1,205 @@
+//! # Standard Scaler
+//! Standardizes each column to zero mean and unit variance, \\( z = \frac{x - \mu}{\sigma} \\).
+
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Transformer};
+use crate::error::Failure;
+use crate::linalg::BaseMatrix;
+use crate::numbers::Real;
+
+/// Standard scaler parameters
+#[derive(Clone, Debug)]
+pub struct StandardScalerParameters {
+    /// Subtract the column mean.
+    pub with_mean: bool,
+    /// Divide by the column standard deviation.
+    pub with_std: bool,
+}
+
+impl Default for StandardScalerParameters {
+    fn default() -> Self {
+        StandardScalerParameters {
+            with_mean: true,
+            with_std: true,
+        }
+    }
+}
+
+/// Per-column standardization. Columns with zero variance are centered but left unscaled.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StandardScaler<T: Real, M: BaseMatrix<T>> {
+    mean: Vec<T>,
+    scale: Vec<T>,
+    _phantom: PhantomData<M>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for StandardScaler<T, M> {
+    fn default() -> Self {
+        StandardScaler {
+            mean: Vec::new(),
+            scale: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> StandardScaler<T, M> {
+    /// Per-column offsets subtracted by `transform`.
+    pub fn mean(&self) -> &[T] {
+        &self.mean
+    }
+
+    /// Per-column divisors applied by `transform`.
+    pub fn scale(&self) -> &[T] {
+        &self.scale
+    }
+
+    /// Map standardized data back to the original feature space.
+    pub fn inverse_transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = self.check_shape(x)?;
+        let mut r = M::zeros(nrows, ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                r.set(i, j, x.get(i, j) * self.scale[j] + self.mean[j]);
+            }
+        }
+        Ok(r)
+    }
+
+    fn check_shape(&self, x: &M) -> Result<(usize, usize), Failure> {
+        let (nrows, ncols) = x.shape();
+        if ncols != self.mean.len() {
+            return Err(Failure::transform(&format!(
+                "x has {} features, scaler was fitted with {}",
+                ncols,
+                self.mean.len()
+            )));
+        }
+        Ok((nrows, ncols))
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, StandardScalerParameters, Failure>
+    for StandardScaler<T, M>
+{
+    /// Learn per-column mean and standard deviation of `x`, `y` is ignored.
+    fn fit(self, x: &M, _y: &M, fit_params: StandardScalerParameters) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        if nrows == 0 {
+            return Err(Failure::fit("x must have at least one row"));
+        }
+        let n = T::from_usize(nrows).unwrap();
+        let mut mean = vec![T::zero(); ncols];
+        let mut scale = vec![T::one(); ncols];
+
+        for j in 0..ncols {
+            let first = x.get(0, j);
+            let mut mu = T::zero();
+            let mut constant = true;
+            for i in 0..nrows {
+                let xij = x.get(i, j);
+                mu += xij;
+                constant &= xij == first;
+            }
+            // Constant columns are centered on their exact value, the rounded mean would leave a residue.
+            mu = if constant { first } else { mu / n };
+
+            let mut sum = T::zero();
+            for i in 0..nrows {
+                sum += (x.get(i, j) - mu).square();
+            }
+            let std = (sum / n).sqrt();
+
+            if fit_params.with_mean {
+                mean[j] = mu;
+            }
+            if fit_params.with_std && !constant && std > T::epsilon() * mu.abs() {
+                scale[j] = std;
+            }
+        }
+
+        Ok(StandardScaler {
+            mean,
+            scale,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Transformer<M, Failure> for StandardScaler<T, M> {
+    fn transform(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = self.check_shape(x)?;
+        let mut r = M::zeros(nrows, ncols);
+        for i in 0..nrows {
+            for j in 0..ncols {
+                r.set(i, j, (x.get(i, j) - self.mean[j]) / self.scale[j]);
+            }
+        }
+        Ok(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::BaseVector;
+
+    #[test]
+    fn fit_transform() {
+        let x: Vec<Vec<f64>> = BaseMatrix::from_2d_array(&[
+            &[1., 10., 5.],
+            &[2., 20., 5.],
+            &[3., 60., 5.],
+            &[6., 30., 5.],
+        ]);
+        let scaler = StandardScaler::default()
+            .fit(&x, &x, Default::default())
+            .unwrap();
+        let z = scaler.transform(&x).unwrap();
+
+        for j in 0..2 {
+            let col: Vec<f64> = z.get_col(j);
+            assert!(col.mean().abs() < 1e-12);
+            assert!((col.std() - 1.).abs() < 1e-12);
+        }
+        let constant: Vec<f64> = z.get_col(2);
+        assert_eq!(constant, vec![0., 0., 0., 0.]);
+
+        let x_back = scaler.inverse_transform(&z).unwrap();
+        for i in 0..4 {
+            assert!(x_back[i].approximate_eq(&x[i], 1e-12));
+        }
+    }
+
+    #[test]
+    fn fit_transform_in_one_step() {
+        let x: Vec<Vec<f64>> =
+            BaseMatrix::from_2d_array(&[&[1., 10.], &[2., 20.], &[3., 60.], &[6., 30.]]);
+        let z = StandardScaler::default()
+            .fit_transform(&x, &x, Default::default())
+            .unwrap();
+
+        for j in 0..2 {
+            let col: Vec<f64> = z.get_col(j);
+            assert!(col.mean().abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn constant_non_dyadic_column() {
+        for &nrows in &[3, 6] {
+            let x: Vec<Vec<f64>> = (0..nrows).map(|i| vec![0.1, i as f64]).collect();
+            let scaler = StandardScaler::default()
+                .fit(&x, &x, Default::default())
+                .unwrap();
+            assert_eq!(scaler.scale()[0], 1.);
+
+            let z = scaler.transform(&x).unwrap();
+            let constant: Vec<f64> = z.get_col(0);
+            assert_eq!(constant, vec![0.; nrows]);
+        }
+    }
+}
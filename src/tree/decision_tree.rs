@@ -0,0 +1,506 @@
+//! # Decision Tree Classifier
+//! [CART](https://en.wikipedia.org/wiki/Decision_tree_learning) classifier that greedily picks the axis-aligned split
+//! with the largest impurity decrease at every node. Nodes are stored in a flat arena and reference their children by
+//! index.
+
+use std::marker::PhantomData;
+
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use crate::base::{BaseEstimator, Classifier};
+use crate::error::Failure;
+use crate::linalg::{BaseMatrix, BaseVector};
+use crate::metrics::impurity::{entropy_from_counts, gini_from_counts};
+use crate::numbers::Real;
+
+/// Impurity measure used to score candidate splits
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SplitCriterion {
+    /// [Gini impurity](https://en.wikipedia.org/wiki/Decision_tree_learning#Gini_impurity), \\( 1 - \sum_c p_c^2 \\)
+    Gini,
+    /// [Entropy](https://en.wikipedia.org/wiki/Decision_tree_learning#Information_gain), \\( -\sum_c p_c \log_2 p_c \\)
+    Entropy,
+}
+
+/// Decision tree classifier parameters
+#[derive(Clone, Debug)]
+pub struct DecisionTreeClassifierParameters {
+    /// Impurity measure.
+    pub criterion: SplitCriterion,
+    /// Maximum depth of the tree, the root has depth 0. `None` grows until leaves are pure.
+    pub max_depth: Option<usize>,
+    /// Minimum number of samples a node needs to be split.
+    pub min_samples_split: usize,
+    /// Number of features drawn at random, without replacement, as split candidates at every node. `None`, or a value
+    /// not smaller than the number of features, considers all of them.
+    pub max_features: Option<usize>,
+    /// Seed of the generator drawing the candidate features.
+    pub seed: u64,
+}
+
+impl Default for DecisionTreeClassifierParameters {
+    fn default() -> Self {
+        DecisionTreeClassifierParameters {
+            criterion: SplitCriterion::Gini,
+            max_depth: None,
+            min_samples_split: 2,
+            max_features: None,
+            seed: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Split<T: Real> {
+    feature: usize,
+    threshold: T,
+    left: usize,
+    right: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct Node<T: Real> {
+    prediction: T,
+    impurity: T,
+    n_samples: usize,
+    split: Option<Split<T>>,
+}
+
+/// CART decision tree classifier
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecisionTreeClassifier<T: Real, M: BaseMatrix<T>> {
+    nodes: Vec<Node<T>>,
+    n_features: usize,
+    _phantom: PhantomData<M>,
+}
+
+impl<T: Real, M: BaseMatrix<T>> Default for DecisionTreeClassifier<T, M> {
+    fn default() -> Self {
+        DecisionTreeClassifier {
+            nodes: Vec::new(),
+            n_features: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> DecisionTreeClassifier<T, M> {
+    /// Depth of the fitted tree, a single leaf has depth 0.
+    pub fn depth(&self) -> usize {
+        fn depth_of<T: Real>(nodes: &[Node<T>], i: usize) -> usize {
+            match &nodes[i].split {
+                Some(s) => 1 + depth_of(nodes, s.left).max(depth_of(nodes, s.right)),
+                None => 0,
+            }
+        }
+        if self.nodes.is_empty() {
+            0
+        } else {
+            depth_of(&self.nodes, 0)
+        }
+    }
+
+    /// Total weighted impurity decrease of the splits on every feature, normalized to sum to 1. Features that are
+    /// never split on get 0, and a tree consisting of a single leaf yields all zeros.
+    /// Panics if the tree has not been fitted.
+    pub fn feature_importances<V: BaseVector<T>>(&self) -> V {
+        assert!(
+            !self.nodes.is_empty(),
+            "feature_importances: tree is not fitted"
+        );
+        let mut importances = V::zeros(self.n_features);
+        for node in self.nodes.iter() {
+            if let Some(split) = &node.split {
+                let (left, right) = (&self.nodes[split.left], &self.nodes[split.right]);
+                let decrease = T::from_usize(node.n_samples).unwrap() * node.impurity
+                    - T::from_usize(left.n_samples).unwrap() * left.impurity
+                    - T::from_usize(right.n_samples).unwrap() * right.impurity;
+                importances.add_element_mut(split.feature, decrease);
+            }
+        }
+        let total = importances.sum();
+        if total > T::zero() {
+            importances.div_scalar_mut(total);
+        }
+        importances
+    }
+
+    fn predict_row(&self, x: &M, row: usize) -> T {
+        let mut node = &self.nodes[0];
+        while let Some(split) = &node.split {
+            node = if x.get(row, split.feature) <= split.threshold {
+                &self.nodes[split.left]
+            } else {
+                &self.nodes[split.right]
+            };
+        }
+        node.prediction
+    }
+}
+
+/// Grows the tree over class indices rather than raw labels.
+struct Builder<'a, T: Real, M: BaseMatrix<T>> {
+    x: &'a M,
+    y: Vec<usize>,
+    classes: Vec<T>,
+    params: DecisionTreeClassifierParameters,
+    rng: StdRng,
+    nodes: Vec<Node<T>>,
+}
+
+impl<'a, T: Real, M: BaseMatrix<T>> Builder<'a, T, M> {
+    fn impurity(&self, counts: &[usize], n: usize) -> T {
+        match self.params.criterion {
+            SplitCriterion::Gini => gini_from_counts(counts, n),
+            SplitCriterion::Entropy => entropy_from_counts(counts, n),
+        }
+    }
+
+    fn counts(&self, samples: &[usize]) -> Vec<usize> {
+        let mut counts = vec![0; self.classes.len()];
+        for &i in samples {
+            counts[self.y[i]] += 1;
+        }
+        counts
+    }
+
+    /// Features considered at the next node, all of them unless `max_features` restricts the choice.
+    fn candidate_features(&mut self) -> Vec<usize> {
+        let (_, ncols) = self.x.shape();
+        match self.params.max_features {
+            Some(k) if k < ncols => sample(&mut self.rng, ncols, k).into_vec(),
+            _ => (0..ncols).collect(),
+        }
+    }
+
+    /// Best `(feature, threshold, weighted child impurity)` over the candidate features, if any split separates the
+    /// samples.
+    // `Option::is_none_or` would raise the minimum supported Rust version to 1.82.
+    #[allow(clippy::unnecessary_map_or)]
+    fn best_split(&mut self, samples: &[usize], parent: T) -> Option<(usize, T, T)> {
+        let n = samples.len();
+        let mut best: Option<(usize, T, T)> = None;
+        for feature in self.candidate_features() {
+            let mut sorted = samples.to_vec();
+            sorted.sort_by(|&a, &b| {
+                self.x
+                    .get(a, feature)
+                    .partial_cmp(&self.x.get(b, feature))
+                    .unwrap()
+            });
+            let mut left = vec![0; self.classes.len()];
+            let mut right = self.counts(samples);
+            for k in 1..n {
+                let moved = self.y[sorted[k - 1]];
+                left[moved] += 1;
+                right[moved] -= 1;
+                let lo = self.x.get(sorted[k - 1], feature);
+                let hi = self.x.get(sorted[k], feature);
+                if lo == hi {
+                    continue;
+                }
+                let weighted = (T::from_usize(k).unwrap() * self.impurity(&left, k)
+                    + T::from_usize(n - k).unwrap() * self.impurity(&right, n - k))
+                    / T::from_usize(n).unwrap();
+                if weighted < parent && best.map_or(true, |(_, _, b)| weighted < b) {
+                    // Halving first avoids overflow near `T::max_value()`, and adjacent floats can round the
+                    // midpoint up to `hi`, which would send every sample left.
+                    let mid = lo * T::half() + hi * T::half();
+                    let threshold = if mid >= hi { lo } else { mid };
+                    best = Some((feature, threshold, weighted));
+                }
+            }
+        }
+        best
+    }
+
+    #[allow(clippy::unnecessary_map_or)]
+    fn grow(&mut self, samples: Vec<usize>, depth: usize) -> usize {
+        let counts = self.counts(&samples);
+        let mut majority = 0;
+        for c in 1..counts.len() {
+            if counts[c] > counts[majority] {
+                majority = c;
+            }
+        }
+        let impurity = self.impurity(&counts, samples.len());
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            prediction: self.classes[majority],
+            impurity,
+            n_samples: samples.len(),
+            split: None,
+        });
+
+        let can_split = impurity > T::zero()
+            && samples.len() >= self.params.min_samples_split
+            && self.params.max_depth.map_or(true, |d| depth < d);
+        if !can_split {
+            return id;
+        }
+        if let Some((feature, threshold, _)) = self.best_split(&samples, impurity) {
+            let (left, right): (Vec<usize>, Vec<usize>) = samples
+                .into_iter()
+                .partition(|&i| self.x.get(i, feature) <= threshold);
+            if left.is_empty() || right.is_empty() {
+                return id;
+            }
+            let left = self.grow(left, depth + 1);
+            let right = self.grow(right, depth + 1);
+            self.nodes[id].split = Some(Split {
+                feature,
+                threshold,
+                left,
+                right,
+            });
+        }
+        id
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> BaseEstimator<M, DecisionTreeClassifierParameters, Failure>
+    for DecisionTreeClassifier<T, M>
+{
+    fn fit(
+        self,
+        x: &M,
+        y: &M,
+        fit_params: DecisionTreeClassifierParameters,
+    ) -> Result<Self, Failure> {
+        let (nrows, ncols) = x.shape();
+        let (y_nrows, y_ncols) = y.shape();
+        if y_nrows != nrows || y_ncols != 1 {
+            return Err(Failure::fit(&format!(
+                "y must be a {}x1 column matrix, got {}x{}",
+                nrows, y_nrows, y_ncols
+            )));
+        }
+        if nrows == 0 {
+            return Err(Failure::fit("x must have at least one sample"));
+        }
+        if fit_params.min_samples_split < 2 {
+            return Err(Failure::fit(&format!(
+                "min_samples_split must be at least 2, got {}",
+                fit_params.min_samples_split
+            )));
+        }
+        if fit_params.max_features == Some(0) {
+            return Err(Failure::fit("max_features must be at least 1"));
+        }
+        for i in 0..nrows {
+            if y.get(i, 0).is_nan() {
+                return Err(Failure::fit(&format!("y has a NaN label at row {}", i)));
+            }
+            if let Some(j) = (0..ncols).find(|&j| x.get(i, j).is_nan()) {
+                return Err(Failure::fit(&format!("x has a NaN at ({}, {})", i, j)));
+            }
+        }
+
+        let mut classes: Vec<T> = (0..nrows).map(|i| y.get(i, 0)).collect();
+        classes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        classes.dedup();
+        let labels = (0..nrows)
+            .map(|i| classes.iter().position(|&c| c == y.get(i, 0)).unwrap())
+            .collect();
+
+        let mut builder = Builder {
+            x,
+            y: labels,
+            classes,
+            rng: StdRng::seed_from_u64(fit_params.seed),
+            params: fit_params,
+            nodes: Vec::new(),
+        };
+        builder.grow((0..nrows).collect(), 0);
+
+        Ok(DecisionTreeClassifier {
+            nodes: builder.nodes,
+            n_features: ncols,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Real, M: BaseMatrix<T>> Classifier<M, Failure> for DecisionTreeClassifier<T, M> {
+    fn predict(&self, x: &M) -> Result<M, Failure> {
+        let (nrows, ncols) = x.shape();
+        if ncols != self.n_features {
+            return Err(Failure::predict(&format!(
+                "x has {} features, model was fitted with {}",
+                ncols, self.n_features
+            )));
+        }
+        let mut labels = M::zeros(nrows, 1);
+        for i in 0..nrows {
+            labels.set(i, 0, self.predict_row(x, i));
+        }
+        Ok(labels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::dense_matrix::DenseMatrix;
+    use crate::linalg::dense_vector::DenseVector;
+
+    fn data() -> (DenseMatrix<f64>, DenseMatrix<f64>) {
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 1.],
+            &[2., 1.],
+            &[1., 3.],
+            &[2., 4.],
+            &[5., 1.],
+            &[6., 2.],
+            &[5., 5.],
+            &[7., 6.],
+        ]);
+        let y =
+            DenseMatrix::from_2d_array(&[&[0.], &[0.], &[1.], &[1.], &[2.], &[2.], &[2.], &[2.]]);
+        (x, y)
+    }
+
+    #[test]
+    fn separates_training_data() {
+        let (x, y) = data();
+        for &criterion in &[SplitCriterion::Gini, SplitCriterion::Entropy] {
+            let tree = DecisionTreeClassifier::default()
+                .fit(
+                    &x,
+                    &y,
+                    DecisionTreeClassifierParameters {
+                        criterion,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+            assert_eq!(tree.depth(), 2);
+            assert_eq!(tree.predict(&x).unwrap(), y);
+        }
+    }
+
+    #[test]
+    fn splits_near_max_and_adjacent_values() {
+        let x = DenseMatrix::from_2d_array(&[&[1e308], &[1.5e308], &[1e308], &[1.5e308]]);
+        let y = DenseMatrix::from_2d_array(&[&[0.], &[1.], &[0.], &[1.]]);
+        let tree = DecisionTreeClassifier::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        assert_eq!(tree.depth(), 1);
+        assert_eq!(tree.predict(&x).unwrap(), y);
+
+        let next = f64::from_bits(1f64.to_bits() + 1);
+        let x = DenseMatrix::from_2d_array(&[&[1.], &[next], &[1.], &[next]]);
+        let tree = DecisionTreeClassifier::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+        assert_eq!(tree.predict(&x).unwrap(), y);
+    }
+
+    #[test]
+    fn respects_max_depth() {
+        let (x, y) = data();
+        let tree = DecisionTreeClassifier::default()
+            .fit(
+                &x,
+                &y,
+                DecisionTreeClassifierParameters {
+                    max_depth: Some(1),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert_eq!(tree.depth(), 1);
+
+        let y_hat = tree.predict(&x).unwrap();
+        assert_eq!(y_hat.get(4, 0), 2.);
+        assert_eq!(y_hat.get(0, 0), y_hat.get(2, 0));
+    }
+
+    #[test]
+    fn feature_importances() {
+        // only the first feature separates the classes
+        let x = DenseMatrix::from_2d_array(&[
+            &[1., 5.],
+            &[2., 1.],
+            &[3., 4.],
+            &[4., 2.],
+            &[6., 3.],
+            &[7., 5.],
+            &[8., 1.],
+            &[9., 2.],
+        ]);
+        let y =
+            DenseMatrix::from_2d_array(&[&[0.], &[0.], &[0.], &[0.], &[1.], &[1.], &[1.], &[1.]]);
+        let tree = DecisionTreeClassifier::default()
+            .fit(&x, &y, Default::default())
+            .unwrap();
+
+        let importances: DenseVector<f64> = tree.feature_importances();
+        assert!((importances.get(0) - 1.).abs() < 1e-12);
+        assert_eq!(importances.get(1), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "feature_importances: tree is not fitted")]
+    fn feature_importances_unfitted() {
+        let tree: DecisionTreeClassifier<f64, DenseMatrix<f64>> = Default::default();
+        let _: DenseVector<f64> = tree.feature_importances();
+    }
+
+    #[test]
+    fn max_features() {
+        let (x, y) = data();
+        let fit = |max_features, seed| {
+            DecisionTreeClassifier::default()
+                .fit(
+                    &x,
+                    &y,
+                    DecisionTreeClassifierParameters {
+                        max_features,
+                        seed,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+        };
+        // drawing every feature is the same as considering all of them
+        assert_eq!(fit(Some(2), 7).predict(&x).unwrap(), y);
+        assert_eq!(
+            fit(Some(1), 3).predict(&x).unwrap(),
+            fit(Some(1), 3).predict(&x).unwrap()
+        );
+
+        let err = DecisionTreeClassifier::default()
+            .fit(
+                &x,
+                &y,
+                DecisionTreeClassifierParameters {
+                    max_features: Some(0),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(err, Failure::fit("max_features must be at least 1"));
+    }
+
+    #[test]
+    fn rejects_nan() {
+        let (mut x, y) = data();
+        x.set(3, 1, f64::NAN);
+        let err = DecisionTreeClassifier::default()
+            .fit(&x, &y, Default::default())
+            .unwrap_err();
+        assert_eq!(err, Failure::fit("x has a NaN at (3, 1)"));
+
+        let (x, mut y) = data();
+        y.set(5, 0, f64::NAN);
+        let err = DecisionTreeClassifier::default()
+            .fit(&x, &y, Default::default())
+            .unwrap_err();
+        assert_eq!(err, Failure::fit("y has a NaN label at row 5"));
+    }
+}
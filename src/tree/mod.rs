@@ -0,0 +1,4 @@
+//! # Decision Trees
+//! Estimators that partition the feature space with axis-aligned splits.
+
+pub mod decision_tree;